@@ -3,18 +3,18 @@
 use std::rc::Rc;
 
 use crate::avm2::class::AllocatorFn;
-use crate::avm2::error::make_error_1107;
+use crate::avm2::error::{make_error_1023, make_error_1107};
 use crate::avm2::function::Executable;
 use crate::avm2::globals::SystemClasses;
 use crate::avm2::method::{Method, NativeMethodImpl};
 use crate::avm2::script::{Script, TranslationUnit};
 use crate::context::{GcContext, UpdateContext};
 use crate::display_object::{DisplayObject, DisplayObjectWeak, TDisplayObject};
-use crate::string::AvmString;
+use crate::string::{AvmString, WStr};
 use crate::tag_utils::SwfMovie;
 use crate::PlayerRuntime;
 
-use fnv::FnvHashMap;
+use fnv::{FnvHashMap, FnvHashSet};
 use gc_arena::{Collect, GcCell, Mutation};
 use std::sync::Arc;
 use swf::avm2::read::Reader;
@@ -63,6 +63,8 @@ mod script;
 pub mod specification;
 mod string;
 mod stubs;
+#[cfg(test)]
+mod test_utils;
 mod traits;
 mod value;
 pub mod vector;
@@ -91,7 +93,79 @@ use self::object::WeakObject;
 use self::scope::Scope;
 use num_traits::FromPrimitive;
 
-const BROADCAST_WHITELIST: [&str; 4] = ["enterFrame", "exitFrame", "frameConstructed", "render"];
+/// A function that can provide a class for a name that is not defined in any
+/// loaded domain.
+///
+/// This is consulted by `Domain::get_defined_value` as a last resort, after
+/// normal lookup finds nothing, allowing classes to be provided that are not
+/// defined by any loaded ABC. The resolver may capture state, and is given an
+/// activation so that it can construct the classes it provides.
+pub type ClassResolverFn =
+    Rc<dyn for<'gc> Fn(&mut Activation<'_, 'gc>, &Multiname<'gc>) -> Option<ClassObject<'gc>>>;
+
+/// The events that are broadcast to every registered listener, rather than
+/// being dispatched through the display list.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BroadcastEvent {
+    EnterFrame,
+    ExitFrame,
+    FrameConstructed,
+    Render,
+}
+
+impl BroadcastEvent {
+    /// Look up the broadcast event with the given event name, if any.
+    pub fn from_name(name: &WStr) -> Option<Self> {
+        if name == b"enterFrame" {
+            Some(Self::EnterFrame)
+        } else if name == b"exitFrame" {
+            Some(Self::ExitFrame)
+        } else if name == b"frameConstructed" {
+            Some(Self::FrameConstructed)
+        } else if name == b"render" {
+            Some(Self::Render)
+        } else {
+            None
+        }
+    }
+
+    /// The event name of this broadcast event.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::EnterFrame => "enterFrame",
+            Self::ExitFrame => "exitFrame",
+            Self::FrameConstructed => "frameConstructed",
+            Self::Render => "render",
+        }
+    }
+}
+
+/// The default limit on how deep the call stack may get before calls throw a
+/// stack overflow error.
+///
+/// Flash Player throws once its own native stack runs low, rather than
+/// counting calls. This is the default recursion limit that Adobe's compilers
+/// write into a movie's `ScriptLimits` tag, which is deep enough for the
+/// recursion that content relies on. Hosts with a native stack too small for
+/// this many calls should lower it with
+/// `PlayerBuilder::with_avm2_max_recursion_depth`.
+pub const DEFAULT_MAX_RECURSION_DEPTH: usize = 1000;
+
+/// How many calls past `max_recursion_depth` may be made while constructing a
+/// stack overflow error, since the error's constructor needs room on the call
+/// stack too.
+const STACK_OVERFLOW_ERROR_HEADROOM: usize = 8;
+
+/// The default limit on how many values the operand stack may hold across
+/// every activation.
+pub const DEFAULT_MAX_STACK_SIZE: usize = 1 << 20;
+
+/// The default limit on how many scopes the scope stack may hold across every
+/// activation.
+///
+/// Scopes are only pushed by `pushscope` and `pushwith`, and a method rarely
+/// holds more than a few, so this is far lower than the operand stack limit.
+pub const DEFAULT_MAX_SCOPE_DEPTH: usize = 1 << 16;
 
 /// The state of an AVM2 interpreter.
 #[derive(Collect)]
@@ -113,6 +187,22 @@ pub struct Avm2<'gc> {
     /// The current call stack of the player.
     call_stack: GcCell<'gc, CallStack<'gc>>,
 
+    /// The deepest the call stack may get before calls throw a stack
+    /// overflow error, instead of overflowing the native stack.
+    max_recursion_depth: usize,
+
+    /// Whether a stack overflow error is being constructed, which may use the
+    /// calls reserved by `STACK_OVERFLOW_ERROR_HEADROOM`.
+    constructing_stack_overflow_error: bool,
+
+    /// The most values that may be pushed onto the operand stack. Further
+    /// pushes throw a stack overflow error.
+    max_stack_size: usize,
+
+    /// The most scopes that may be pushed onto the scope stack. Further
+    /// pushes throw a stack overflow error.
+    max_scope_depth: usize,
+
     /// This domain is used exclusively for classes from playerglobals
     playerglobals_domain: Domain<'gc>,
 
@@ -160,6 +250,10 @@ pub struct Avm2<'gc> {
     #[collect(require_static)]
     native_call_handler_table: &'static [Option<(&'static str, NativeMethodImpl)>],
 
+    /// The fallback used to resolve classes missing from every domain.
+    #[collect(require_static)]
+    class_resolver: Option<ClassResolverFn>,
+
     /// A list of objects which are capable of receiving broadcasts.
     ///
     /// Certain types of events are "broadcast events" that are emitted on all
@@ -167,6 +261,12 @@ pub struct Avm2<'gc> {
     /// currently present on the display list. This list keeps track of that.
     broadcast_list: FnvHashMap<AvmString<'gc>, Vec<WeakObject<'gc>>>,
 
+    /// The names of the events that are broadcast, rather than dispatched, on
+    /// top of the built-in `BroadcastEvent`s.
+    ///
+    /// Names are added to this with `register_broadcast_type`.
+    custom_broadcast_types: FnvHashSet<AvmString<'gc>>,
+
     /// The list of 'orphan' objects - these objects have no parent,
     /// so we need to manually run their frames in `run_all_phases_avm2` to match
     /// Flash's behavior. Clips are added to this list with `add_orphan_movie`.
@@ -186,10 +286,18 @@ pub struct Avm2<'gc> {
     #[collect(require_static)]
     pub root_api_version: ApiVersion,
 
+    /// An API version to use for the root movie clip instead of the one
+    /// derived from its SWF version, if configured.
+    #[collect(require_static)]
+    pinned_api_version: Option<ApiVersion>,
+
     #[cfg(feature = "avm_debug")]
     pub debug_output: bool,
 
     pub optimizer_enabled: bool,
+
+    /// Whether class verification should log every decision it makes.
+    verbose_verification: bool,
 }
 
 impl<'gc> Avm2<'gc> {
@@ -213,6 +321,10 @@ impl<'gc> Avm2<'gc> {
             stack: Vec::new(),
             scope_stack: Vec::new(),
             call_stack: GcCell::new(context.gc_context, CallStack::new()),
+            max_recursion_depth: DEFAULT_MAX_RECURSION_DEPTH,
+            constructing_stack_overflow_error: false,
+            max_stack_size: DEFAULT_MAX_STACK_SIZE,
+            max_scope_depth: DEFAULT_MAX_SCOPE_DEPTH,
             playerglobals_domain,
             stage_domain,
             system_classes: None,
@@ -250,17 +362,21 @@ impl<'gc> Avm2<'gc> {
             native_instance_allocator_table: Default::default(),
             native_instance_init_table: Default::default(),
             native_call_handler_table: Default::default(),
+            class_resolver: None,
             broadcast_list: Default::default(),
+            custom_broadcast_types: Default::default(),
 
             orphan_objects: Default::default(),
 
             // Set the lowest version for now - this will be overridden when we set our movie
             root_api_version: ApiVersion::AllVersions,
+            pinned_api_version: None,
 
             #[cfg(feature = "avm_debug")]
             debug_output: false,
 
             optimizer_enabled: true,
+            verbose_verification: false,
         }
     }
 
@@ -310,10 +426,7 @@ impl<'gc> Avm2<'gc> {
                     resolved_signature,
                     None,
                 )?;
-                init_activation
-                    .context
-                    .avm2
-                    .push_global_init(init_activation.context.gc_context, script);
+                Avm2::push_global_init(&mut init_activation, script)?;
                 let r = (method.method)(&mut init_activation, scope, &[]);
                 init_activation
                     .context
@@ -322,10 +435,7 @@ impl<'gc> Avm2<'gc> {
                 r?;
             }
             Method::Bytecode(method) => {
-                init_activation
-                    .context
-                    .avm2
-                    .push_global_init(init_activation.context.gc_context, script);
+                Avm2::push_global_init(&mut init_activation, script)?;
                 let r = init_activation.run_actions(method);
                 init_activation
                     .context
@@ -434,6 +544,20 @@ impl<'gc> Avm2<'gc> {
         }
     }
 
+    /// Treat events with the given name as broadcast events from now on.
+    ///
+    /// Registering a name that is already a broadcast event does nothing.
+    pub fn register_broadcast_type(&mut self, name: AvmString<'gc>) {
+        if BroadcastEvent::from_name(&name).is_none() {
+            self.custom_broadcast_types.insert(name);
+        }
+    }
+
+    /// Determine if events with the given name are broadcast events.
+    pub fn is_broadcast_type(&self, name: AvmString<'gc>) -> bool {
+        BroadcastEvent::from_name(&name).is_some() || self.custom_broadcast_types.contains(&name)
+    }
+
     /// Add an object to the broadcast list.
     ///
     /// Each broadcastable event contains it's own broadcast list. You must
@@ -448,10 +572,7 @@ impl<'gc> Avm2<'gc> {
         object: Object<'gc>,
         event_name: AvmString<'gc>,
     ) {
-        if !BROADCAST_WHITELIST
-            .iter()
-            .any(|x| AvmString::from(*x) == event_name)
-        {
+        if !context.avm2.is_broadcast_type(event_name) {
             return;
         }
 
@@ -476,7 +597,7 @@ impl<'gc> Avm2<'gc> {
     /// specific interfaces, and so on.
     ///
     /// Attempts to broadcast a non-broadcast event will do nothing. To add a
-    /// new broadcast type, you must add it to the `BROADCAST_WHITELIST` first.
+    /// new broadcast type, use `register_broadcast_type` first.
     ///
     /// Attempts to broadcast a non-event object will panic.
     pub fn broadcast_event(
@@ -489,10 +610,7 @@ impl<'gc> Avm2<'gc> {
             .map(|e| e.event_type())
             .unwrap_or_else(|| panic!("cannot broadcast non-event object: {:?}", event));
 
-        if !BROADCAST_WHITELIST
-            .iter()
-            .any(|x| AvmString::from(*x) == event_name)
-        {
+        if !context.avm2.is_broadcast_type(event_name) {
             return;
         }
 
@@ -503,6 +621,11 @@ impl<'gc> Avm2<'gc> {
             .or_default()
             .len();
 
+        // Most listeners share a handful of classes, so only check each class
+        // against `on_type` once instead of walking every listener's class chain.
+        let on_class = on_type.inner_class_definition();
+        let mut class_matches: FnvHashMap<Option<ClassObject<'gc>>, bool> = FnvHashMap::default();
+
         for i in 0..el_length {
             let object = context
                 .avm2
@@ -515,7 +638,11 @@ impl<'gc> Avm2<'gc> {
             if let Some(object) = object.and_then(|obj| obj.upgrade(context.gc_context)) {
                 let mut activation = Activation::from_nothing(context.reborrow());
 
-                if object.is_of_type(on_type.inner_class_definition(), &mut activation.context) {
+                let is_of_type = *class_matches
+                    .entry(object.instance_of())
+                    .or_insert_with(|| object.is_of_type(on_class, &mut activation.context));
+
+                if is_of_type {
                     if let Err(err) = events::dispatch_event(&mut activation, object, event) {
                         tracing::error!(
                             "Encountered AVM2 error when broadcasting `{}` event: {:?}",
@@ -528,6 +655,8 @@ impl<'gc> Avm2<'gc> {
             }
         }
         // Once we're done iterating, remove dead weak references from the list.
+        // Handlers may re-enter this function and register or broadcast further
+        // events, so the list must not be compacted while we're still iterating.
         context
             .avm2
             .broadcast_list
@@ -590,13 +719,55 @@ impl<'gc> Avm2<'gc> {
     }
 
     /// Pushes an executable on the call stack
-    pub fn push_call(&self, mc: &Mutation<'gc>, calling: &Executable<'gc>) {
-        self.call_stack.write(mc).push(calling)
+    ///
+    /// If the call stack is already `max_recursion_depth` calls deep, nothing
+    /// is pushed and a catchable stack overflow error is thrown instead.
+    pub fn push_call(
+        activation: &mut Activation<'_, 'gc>,
+        calling: &Executable<'gc>,
+    ) -> Result<(), Error<'gc>> {
+        Self::check_call_stack_depth(activation)?;
+        let call_stack = activation.avm2().call_stack;
+        call_stack
+            .write(activation.context.gc_context)
+            .push(calling);
+        Ok(())
     }
 
     /// Pushes script initializer (global init) on the call stack
-    pub fn push_global_init(&self, mc: &Mutation<'gc>, script: Script<'gc>) {
-        self.call_stack.write(mc).push_global_init(script)
+    ///
+    /// This fails in the same way as `push_call` if the call stack is full.
+    pub fn push_global_init(
+        activation: &mut Activation<'_, 'gc>,
+        script: Script<'gc>,
+    ) -> Result<(), Error<'gc>> {
+        Self::check_call_stack_depth(activation)?;
+        let call_stack = activation.avm2().call_stack;
+        call_stack
+            .write(activation.context.gc_context)
+            .push_global_init(script);
+        Ok(())
+    }
+
+    fn check_call_stack_depth(activation: &mut Activation<'_, 'gc>) -> Result<(), Error<'gc>> {
+        let avm2 = activation.avm2();
+        let depth = avm2.call_stack_depth();
+        if depth < avm2.max_recursion_depth {
+            return Ok(());
+        }
+
+        if avm2.constructing_stack_overflow_error {
+            if depth - avm2.max_recursion_depth < STACK_OVERFLOW_ERROR_HEADROOM {
+                return Ok(());
+            }
+
+            return Err("Ran out of call stack while constructing a stack overflow error".into());
+        }
+
+        avm2.constructing_stack_overflow_error = true;
+        let err = make_error_1023(activation);
+        activation.avm2().constructing_stack_overflow_error = false;
+        Err(err)
     }
 
     /// Pops an executable off the call stack
@@ -608,17 +779,52 @@ impl<'gc> Avm2<'gc> {
         self.call_stack
     }
 
+    /// The number of calls currently on the call stack.
+    pub fn call_stack_depth(&self) -> usize {
+        self.call_stack.read().depth()
+    }
+
+    /// The deepest the call stack may get before calls throw a stack
+    /// overflow error.
+    pub fn max_recursion_depth(&self) -> usize {
+        self.max_recursion_depth
+    }
+
+    pub fn set_max_recursion_depth(&mut self, max_recursion_depth: usize) {
+        self.max_recursion_depth = max_recursion_depth;
+    }
+
+    /// The most values the operand stack may hold.
+    pub fn max_stack_size(&self) -> usize {
+        self.max_stack_size
+    }
+
+    pub fn set_max_stack_size(&mut self, max_stack_size: usize) {
+        self.max_stack_size = max_stack_size;
+    }
+
+    /// The most scopes the scope stack may hold.
+    pub fn max_scope_depth(&self) -> usize {
+        self.max_scope_depth
+    }
+
+    pub fn set_max_scope_depth(&mut self, max_scope_depth: usize) {
+        self.max_scope_depth = max_scope_depth;
+    }
+
     #[cold]
     fn stack_overflow(&self) {
         tracing::warn!("Avm2::push: Stack overflow");
     }
 
     /// Push a value onto the operand stack.
+    ///
+    /// Nothing is pushed, and `Err` is returned, if the operand stack is full.
     #[inline(always)]
-    fn push(&mut self, value: impl Into<Value<'gc>>, depth: usize, max: usize) {
-        if self.stack.len() - depth > max {
+    fn push(&mut self, value: impl Into<Value<'gc>>) -> Result<(), ()> {
+        if self.stack.len() >= self.max_stack_size {
             self.stack_overflow();
-            return;
+            return Err(());
         }
         let mut value = value.into();
         if let Value::Object(o) = value {
@@ -632,6 +838,7 @@ impl<'gc> Avm2<'gc> {
 
         avm_debug!(self, "Stack push {}: {value:?}", self.stack.len());
         self.stack.push(value);
+        Ok(())
     }
 
     /// Push a value onto the operand stack.
@@ -688,6 +895,21 @@ impl<'gc> Avm2<'gc> {
         value
     }
 
+    /// Copy the contents of the operand stack, from bottom to top.
+    ///
+    /// This is intended for debugging tools, and includes the values of every
+    /// activation on the call stack.
+    #[cfg(feature = "avm_debug")]
+    pub fn stack_snapshot(&self) -> Vec<Value<'gc>> {
+        self.stack.clone()
+    }
+
+    /// The number of values on the operand stack.
+    #[cfg(feature = "avm_debug")]
+    pub fn stack_depth(&self) -> usize {
+        self.stack.len()
+    }
+
     fn pop_args(&mut self, arg_count: u32, depth: usize) -> Vec<Value<'gc>> {
         let mut args = vec![Value::Undefined; arg_count as usize];
         for arg in args.iter_mut().rev() {
@@ -696,13 +918,17 @@ impl<'gc> Avm2<'gc> {
         args
     }
 
-    fn push_scope(&mut self, scope: Scope<'gc>, depth: usize, max: usize) {
-        if self.scope_stack.len() - depth > max {
+    /// Push a scope onto the scope stack.
+    ///
+    /// Nothing is pushed, and `Err` is returned, if the scope stack is full.
+    fn push_scope(&mut self, scope: Scope<'gc>) -> Result<(), ()> {
+        if self.scope_stack.len() >= self.max_scope_depth {
             tracing::warn!("Avm2::push_scope: Scope stack overflow");
-            return;
+            return Err(());
         }
 
         self.scope_stack.push(scope);
+        Ok(())
     }
 
     fn pop_scope(&mut self, depth: usize) {
@@ -747,6 +973,42 @@ impl<'gc> Avm2<'gc> {
     pub fn set_optimizer_enabled(&mut self, value: bool) {
         self.optimizer_enabled = value;
     }
+
+    pub fn verbose_verification(&self) -> bool {
+        self.verbose_verification
+    }
+
+    pub fn set_verbose_verification(&mut self, value: bool) {
+        self.verbose_verification = value;
+    }
+
+    pub fn pinned_api_version(&self) -> Option<ApiVersion> {
+        self.pinned_api_version
+    }
+
+    /// Pin the API version used for the root movie clip, ignoring any value
+    /// that does not correspond to a known `ApiVersion`.
+    pub fn set_pinned_api_version(&mut self, version: Option<u16>) {
+        self.pinned_api_version = version.and_then(|version| {
+            let api_version = ApiVersion::from_u16(version);
+            if api_version.is_none() {
+                tracing::warn!("Ignoring unknown pinned API version {version}");
+            }
+            api_version
+        });
+    }
+
+    /// The fallback consulted for names that are not defined in any loaded
+    /// domain, if one has been registered.
+    pub fn class_resolver(&self) -> Option<ClassResolverFn> {
+        self.class_resolver.clone()
+    }
+
+    /// Register a fallback for class names that cannot be resolved in any
+    /// loaded domain.
+    pub fn set_class_resolver(&mut self, resolver: Option<ClassResolverFn>) {
+        self.class_resolver = resolver;
+    }
 }
 
 /// If the provided `DisplayObjectWeak` should have frames run, returns
@@ -763,3 +1025,317 @@ fn valid_orphan<'gc>(
     }
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Activation, Avm2, BroadcastEvent, ClassObject, ClassResolverFn, Error, Multiname, Object,
+        QName, Value, DEFAULT_MAX_SCOPE_DEPTH, DEFAULT_MAX_STACK_SIZE,
+    };
+    use crate::avm2::method::Method;
+    use crate::avm2::object::{EventObject, FunctionObject, TObject};
+    use crate::avm2::scope::Scope;
+    use crate::avm2::test_utils::with_avm2;
+    use crate::player::PlayerBuilder;
+    use crate::string::{AvmString, WStr};
+    use crate::tag_utils::SwfMovie;
+    use gc_arena::{DynamicRoot, Rootable};
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    thread_local! {
+        static EVENTS_RECEIVED: Cell<u32> = const { Cell::new(0) };
+    }
+
+    fn count_event<'gc>(
+        _activation: &mut Activation<'_, 'gc>,
+        _this: Object<'gc>,
+        _args: &[Value<'gc>],
+    ) -> Result<Value<'gc>, Error<'gc>> {
+        EVENTS_RECEIVED.with(|received| received.set(received.get() + 1));
+        Ok(Value::Undefined)
+    }
+
+    /// Create an `EventDispatcher` that counts every `event_name` event it receives.
+    fn counting_dispatcher<'gc>(
+        activation: &mut Activation<'_, 'gc>,
+        event_name: &'static str,
+    ) -> Result<Object<'gc>, Error<'gc>> {
+        let dispatcher = activation
+            .avm2()
+            .classes()
+            .eventdispatcher
+            .construct(activation, &[])?;
+        add_counting_listener(activation, dispatcher, event_name)?;
+
+        Ok(dispatcher)
+    }
+
+    /// Make `dispatcher` count every `event_name` event it receives.
+    fn add_counting_listener<'gc>(
+        activation: &mut Activation<'_, 'gc>,
+        dispatcher: Object<'gc>,
+        event_name: &'static str,
+    ) -> Result<(), Error<'gc>> {
+        let handler = FunctionObject::from_method(
+            activation,
+            Method::from_builtin(count_event, "countEvent", activation.context.gc_context),
+            activation.create_scopechain(),
+            None,
+            None,
+        );
+        dispatcher.call_public_property(
+            "addEventListener",
+            &[event_name.into(), handler.into()],
+            activation,
+        )?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn broadcast_event_names() {
+        for event in [
+            BroadcastEvent::EnterFrame,
+            BroadcastEvent::ExitFrame,
+            BroadcastEvent::FrameConstructed,
+            BroadcastEvent::Render,
+        ] {
+            let name = WStr::from_units(event.as_str().as_bytes());
+            assert_eq!(BroadcastEvent::from_name(name), Some(event));
+        }
+
+        assert_eq!(
+            BroadcastEvent::from_name(WStr::from_units(b"enterframe")),
+            None
+        );
+        assert_eq!(BroadcastEvent::from_name(WStr::from_units(b"click")), None);
+    }
+
+    #[test]
+    fn broadcast_event_prunes_collected_listeners() {
+        let player = PlayerBuilder::new().with_movie(SwfMovie::empty(10)).build();
+        let mut player = player.lock().unwrap();
+
+        let live: DynamicRoot<Rootable![Object<'_>]> =
+            player.mutate_with_update_context(|context| {
+                let mut activation = Activation::from_nothing(context.reborrow());
+                let live = counting_dispatcher(&mut activation, "enterFrame").unwrap();
+                counting_dispatcher(&mut activation, "enterFrame").unwrap();
+                drop(activation);
+
+                let event_name = AvmString::new_utf8(context.gc_context, "enterFrame");
+                assert_eq!(context.avm2.broadcast_list[&event_name].len(), 2);
+
+                context.dynamic_root.stash(context.gc_context, live)
+            });
+
+        player.collect_all_garbage();
+
+        player.mutate_with_update_context(|context| {
+            let live = *context.dynamic_root.fetch(&live);
+            let event = EventObject::bare_default_event(context, "enterFrame");
+            let on_type = context.avm2.classes().eventdispatcher;
+
+            EVENTS_RECEIVED.with(|received| received.set(0));
+            Avm2::broadcast_event(context, event, on_type);
+            assert_eq!(EVENTS_RECEIVED.with(Cell::get), 1);
+
+            let event_name = AvmString::new_utf8(context.gc_context, "enterFrame");
+            let listeners = &context.avm2.broadcast_list[&event_name];
+            assert_eq!(listeners.len(), 1);
+            let listener = listeners[0].upgrade(context.gc_context);
+            assert!(listener.is_some_and(|listener| Object::ptr_eq(listener, live)));
+        });
+    }
+
+    #[test]
+    fn broadcast_event_delivers_custom_types() {
+        let player = PlayerBuilder::new()
+            .with_avm2_broadcast_type("activate")
+            .with_movie(SwfMovie::empty(10))
+            .build();
+        let mut player = player.lock().unwrap();
+
+        player.mutate_with_update_context(|context| {
+            let activate = AvmString::new_utf8(context.gc_context, "activate");
+            let render = AvmString::new_utf8(context.gc_context, "render");
+            let click = AvmString::new_utf8(context.gc_context, "click");
+            assert!(context.avm2.is_broadcast_type(activate));
+            assert!(context.avm2.is_broadcast_type(render));
+            assert!(!context.avm2.is_broadcast_type(click));
+
+            context.avm2.register_broadcast_type(render);
+            assert!(context.avm2.custom_broadcast_types.contains(&activate));
+            assert!(!context.avm2.custom_broadcast_types.contains(&render));
+
+            let mut activation = Activation::from_nothing(context.reborrow());
+            counting_dispatcher(&mut activation, "activate").unwrap();
+            counting_dispatcher(&mut activation, "click").unwrap();
+            drop(activation);
+            assert!(!context.avm2.broadcast_list.contains_key(&click));
+
+            let event = EventObject::bare_default_event(context, "activate");
+            let on_type = context.avm2.classes().eventdispatcher;
+            EVENTS_RECEIVED.with(|received| received.set(0));
+            Avm2::broadcast_event(context, event, on_type);
+            assert_eq!(EVENTS_RECEIVED.with(Cell::get), 1);
+
+            let event = EventObject::bare_default_event(context, "click");
+            Avm2::broadcast_event(context, event, on_type);
+            assert_eq!(EVENTS_RECEIVED.with(Cell::get), 1);
+        });
+    }
+
+    #[test]
+    fn broadcast_event_matches_uncached_type_checks() {
+        let player = PlayerBuilder::new().with_movie(SwfMovie::empty(10)).build();
+        let mut player = player.lock().unwrap();
+
+        player.mutate_with_update_context(|context| {
+            let mut activation = Activation::from_nothing(context.reborrow());
+            for i in 0..5000 {
+                if i % 5 == 0 {
+                    let sprite = activation
+                        .avm2()
+                        .classes()
+                        .sprite
+                        .construct(&mut activation, &[])
+                        .unwrap();
+                    add_counting_listener(&mut activation, sprite, "enterFrame").unwrap();
+                } else {
+                    counting_dispatcher(&mut activation, "enterFrame").unwrap();
+                }
+            }
+            drop(activation);
+
+            let event_name = AvmString::new_utf8(context.gc_context, "enterFrame");
+            let listeners: Vec<Object<'_>> = context.avm2.broadcast_list[&event_name]
+                .iter()
+                .filter_map(|listener| listener.upgrade(context.gc_context))
+                .collect();
+            assert_eq!(listeners.len(), 5000);
+
+            let classes = context.avm2.classes();
+            let on_types = [
+                (classes.eventdispatcher, 5000),
+                (classes.display_object, 1000),
+                (classes.sprite, 1000),
+                (classes.object, 5000),
+            ];
+            for (on_type, receivers) in on_types {
+                // Check each listener against `on_type` separately, as
+                // `broadcast_event` did before it cached results per class.
+                let expected = listeners
+                    .iter()
+                    .filter(|listener| {
+                        listener.is_of_type(on_type.inner_class_definition(), context)
+                    })
+                    .count();
+                assert_eq!(expected, receivers);
+
+                let event = EventObject::bare_default_event(context, "enterFrame");
+                EVENTS_RECEIVED.with(|received| received.set(0));
+                Avm2::broadcast_event(context, event, on_type);
+                assert_eq!(EVENTS_RECEIVED.with(Cell::get) as usize, expected);
+            }
+        });
+    }
+
+    fn error_id<'gc>(
+        activation: &mut Activation<'_, 'gc>,
+        error: Error<'gc>,
+    ) -> Result<Value<'gc>, Error<'gc>> {
+        match error {
+            Error::AvmError(error) => error
+                .as_object()
+                .expect("Thrown error should be an object")
+                .get_public_property("errorID", activation),
+            error => Err(error),
+        }
+    }
+
+    #[test]
+    fn stack_overflow_throws() {
+        with_avm2(10, |activation| {
+            let depth = activation.context.avm2.stack.len();
+            activation.avm2().set_max_stack_size(depth + 2);
+
+            activation.push_stack(1)?;
+            activation.push_stack(2)?;
+            let error = activation
+                .push_stack(3)
+                .expect_err("Pushing past the limit should throw");
+            assert_eq!(activation.context.avm2.stack.len(), depth + 2);
+            assert_eq!(activation.avm2().max_stack_size(), depth + 2);
+            activation.clear_stack();
+            activation.avm2().set_max_stack_size(DEFAULT_MAX_STACK_SIZE);
+            assert_eq!(error_id(activation, error)?, 1023.into());
+
+            let global = activation
+                .avm2()
+                .classes()
+                .object
+                .construct(activation, &[])?;
+            let scope_depth = activation.context.avm2.scope_stack.len();
+            activation.avm2().set_max_scope_depth(scope_depth + 1);
+
+            activation.push_scope(Scope::new(global))?;
+            let error = activation
+                .push_scope(Scope::new(global))
+                .expect_err("Pushing past the limit should throw");
+            assert_eq!(activation.context.avm2.scope_stack.len(), scope_depth + 1);
+            activation.clear_scope();
+            activation
+                .avm2()
+                .set_max_scope_depth(DEFAULT_MAX_SCOPE_DEPTH);
+            assert_eq!(error_id(activation, error)?, 1023.into());
+
+            Ok(())
+        });
+    }
+
+    fn class_resolver(
+        resolver: impl for<'gc> Fn(&mut Activation<'_, 'gc>, &Multiname<'gc>) -> Option<ClassObject<'gc>>
+            + 'static,
+    ) -> ClassResolverFn {
+        Rc::new(resolver)
+    }
+
+    #[test]
+    fn class_resolver_fallback() {
+        with_avm2(10, |activation| {
+            let domain = activation.avm2().stage_domain();
+            let public = activation.avm2().public_namespace_base_version;
+            let synthetic = QName::new(public, "Synthetic");
+            assert!(domain.get_defined_value(activation, synthetic).is_err());
+
+            let calls = Rc::new(Cell::new(0));
+            let resolver_calls = calls.clone();
+            activation
+                .avm2()
+                .set_class_resolver(Some(class_resolver(move |activation, name| {
+                    resolver_calls.set(resolver_calls.get() + 1);
+                    (name.local_name() == Some("Synthetic".into()))
+                        .then(|| activation.avm2().classes().sprite)
+                })));
+
+            let sprite: Object<'_> = activation.avm2().classes().sprite.into();
+            let resolved = domain.get_defined_value(activation, synthetic)?;
+            assert!(resolved
+                .as_object()
+                .is_some_and(|resolved| Object::ptr_eq(resolved, sprite)));
+            assert_eq!(calls.get(), 1);
+
+            // Names that are defined never reach the resolver, and names the
+            // resolver doesn't know still fail to resolve.
+            domain.get_defined_value(activation, QName::new(public, "Object"))?;
+            assert_eq!(calls.get(), 1);
+            let missing = QName::new(public, "Missing");
+            assert!(domain.get_defined_value(activation, missing).is_err());
+            assert_eq!(calls.get(), 2);
+
+            Ok(())
+        });
+    }
+}
@@ -5,8 +5,8 @@ use crate::avm2::class::Class;
 use crate::avm2::domain::Domain;
 use crate::avm2::e4x::{escape_attribute_value, escape_element_value};
 use crate::avm2::error::{
-    make_error_1127, make_error_1506, make_null_or_undefined_error, make_reference_error,
-    type_error, ReferenceErrorCode,
+    make_error_1023, make_error_1127, make_error_1506, make_null_or_undefined_error,
+    make_reference_error, type_error, ReferenceErrorCode,
 };
 use crate::avm2::method::{BytecodeMethod, Method, ResolvedParamConfig};
 use crate::avm2::object::{
@@ -73,6 +73,17 @@ enum FrameControl<'gc> {
     Return(Value<'gc>),
 }
 
+/// Wrap the result of a `Number` arithmetic opcode.
+///
+/// Flash Player stores integral arithmetic results as int atoms, which user
+/// code can observe with `is int`, `getQualifiedClassName` and `describeType`.
+/// Every arithmetic opcode that produces a `Number` goes through here so that
+/// its integral results are `Integer`s too; see `Value::normalize_numeric`.
+#[inline(always)]
+fn arithmetic_result<'gc>(value: f64) -> Value<'gc> {
+    Value::from(value).normalize_numeric()
+}
+
 /// Represents a single activation of a given AVM2 function or keyframe.
 pub struct Activation<'a, 'gc: 'a> {
     /// The instruction index.
@@ -135,12 +146,6 @@ pub struct Activation<'a, 'gc: 'a> {
     /// The index where the scope frame starts.
     scope_depth: usize,
 
-    /// Maximum size for the stack frame.
-    max_stack_size: usize,
-
-    /// Maximum size for the scope frame.
-    max_scope_size: usize,
-
     pub context: UpdateContext<'a, 'gc>,
 }
 
@@ -174,8 +179,6 @@ impl<'a, 'gc> Activation<'a, 'gc> {
             activation_class: None,
             stack_depth: context.avm2.stack.len(),
             scope_depth: context.avm2.scope_stack.len(),
-            max_stack_size: 0,
-            max_scope_size: 0,
             context,
         }
     }
@@ -203,8 +206,6 @@ impl<'a, 'gc> Activation<'a, 'gc> {
             activation_class: None,
             stack_depth: context.avm2.stack.len(),
             scope_depth: context.avm2.scope_stack.len(),
-            max_stack_size: 0,
-            max_scope_size: 0,
             context,
         }
     }
@@ -217,17 +218,13 @@ impl<'a, 'gc> Activation<'a, 'gc> {
     ) -> Result<Self, Error<'gc>> {
         let (method, global_object, domain) = script.init();
 
-        let (num_locals, max_stack, max_scope) = match method {
-            Method::Native { .. } => (0, 0, 0),
+        let num_locals = match method {
+            Method::Native { .. } => 0,
             Method::Bytecode(bytecode) => {
                 let body = bytecode
                     .body()
                     .ok_or("Cannot execute non-native method (for script) without body")?;
-                (
-                    body.num_locals,
-                    body.max_stack,
-                    body.max_scope_depth - body.init_scope_depth,
-                )
+                body.num_locals
             }
         };
         let mut local_registers = RegisterSet::new(num_locals + 1);
@@ -268,8 +265,6 @@ impl<'a, 'gc> Activation<'a, 'gc> {
             activation_class,
             stack_depth: context.avm2.stack.len(),
             scope_depth: context.avm2.scope_stack.len(),
-            max_stack_size: max_stack as usize,
-            max_scope_size: max_scope as usize,
             context,
         };
 
@@ -461,8 +456,6 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         self.activation_class = activation_class;
         self.stack_depth = self.context.avm2.stack.len();
         self.scope_depth = self.context.avm2.scope_stack.len();
-        self.max_stack_size = body.max_stack as usize;
-        self.max_scope_size = (body.max_scope_depth - body.init_scope_depth) as usize;
 
         // Everything is now setup for the verifier to run
         if method.verified_info.read().is_none() {
@@ -565,8 +558,6 @@ impl<'a, 'gc> Activation<'a, 'gc> {
             activation_class: None,
             stack_depth: context.avm2.stack.len(),
             scope_depth: context.avm2.scope_stack.len(),
-            max_stack_size: 0,
-            max_scope_size: 0,
             context,
         }
     }
@@ -674,11 +665,16 @@ impl<'a, 'gc> Activation<'a, 'gc> {
     }
 
     /// Pushes a value onto the operand stack.
+    ///
+    /// This throws a stack overflow error if the operand stack is already
+    /// full; see `Avm2::set_max_stack_size`.
     #[inline]
-    pub fn push_stack(&mut self, value: impl Into<Value<'gc>>) {
-        let stack_depth = self.stack_depth;
-        let max_stack_size = self.max_stack_size;
-        self.avm2().push(value.into(), stack_depth, max_stack_size)
+    pub fn push_stack(&mut self, value: impl Into<Value<'gc>>) -> Result<(), Error<'gc>> {
+        if self.avm2().push(value.into()).is_err() {
+            return Err(self.stack_overflow_error());
+        }
+
+        Ok(())
     }
 
     /// Pushes a value onto the operand stack, without running some checks.
@@ -704,11 +700,30 @@ impl<'a, 'gc> Activation<'a, 'gc> {
     }
 
     /// Pushes a scope onto the scope stack.
+    ///
+    /// This throws a stack overflow error if the scope stack is already full;
+    /// see `Avm2::set_max_scope_depth`.
     #[inline]
-    pub fn push_scope(&mut self, scope: Scope<'gc>) {
-        let scope_depth = self.scope_depth;
-        let max_scope_size = self.max_scope_size;
-        self.avm2().push_scope(scope, scope_depth, max_scope_size)
+    pub fn push_scope(&mut self, scope: Scope<'gc>) -> Result<(), Error<'gc>> {
+        if self.avm2().push_scope(scope).is_err() {
+            return Err(self.stack_overflow_error());
+        }
+
+        Ok(())
+    }
+
+    /// Create the error thrown when the operand or scope stack overflows.
+    #[cold]
+    fn stack_overflow_error(&mut self) -> Error<'gc> {
+        // Constructing the error needs room on both stacks.
+        let max_stack_size = self.avm2().max_stack_size();
+        let max_scope_depth = self.avm2().max_scope_depth();
+        self.avm2().set_max_stack_size(usize::MAX);
+        self.avm2().set_max_scope_depth(usize::MAX);
+        let err = make_error_1023(self);
+        self.avm2().set_max_stack_size(max_stack_size);
+        self.avm2().set_max_scope_depth(max_scope_depth);
+        err
     }
 
     /// Pops a scope off of the scope stack.
@@ -841,7 +856,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
 
                 if matches {
                     self.clear_stack();
-                    self.push_stack(error);
+                    self.push_stack(error)?;
 
                     self.clear_scope();
                     self.ip = e.target_offset as i32;
@@ -1079,22 +1094,22 @@ impl<'a, 'gc> Activation<'a, 'gc> {
     }
 
     fn op_push_byte(&mut self, value: i8) -> Result<FrameControl<'gc>, Error<'gc>> {
-        self.push_stack(value as i32);
+        self.push_stack(value as i32)?;
         Ok(FrameControl::Continue)
     }
 
     fn op_push_double(&mut self, value: f64) -> Result<FrameControl<'gc>, Error<'gc>> {
-        self.push_stack(value);
+        self.push_stack(value)?;
         Ok(FrameControl::Continue)
     }
 
     fn op_push_false(&mut self) -> Result<FrameControl<'gc>, Error<'gc>> {
-        self.push_stack(false);
+        self.push_stack(false)?;
         Ok(FrameControl::Continue)
     }
 
     fn op_push_int(&mut self, value: i32) -> Result<FrameControl<'gc>, Error<'gc>> {
-        self.push_stack(value);
+        self.push_stack(value)?;
         Ok(FrameControl::Continue)
     }
 
@@ -1106,42 +1121,42 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         let ns = self.pool_namespace(method, value)?;
         let ns_object = NamespaceObject::from_namespace(self, ns)?;
 
-        self.push_stack(ns_object);
+        self.push_stack(ns_object)?;
         Ok(FrameControl::Continue)
     }
 
     fn op_push_nan(&mut self) -> Result<FrameControl<'gc>, Error<'gc>> {
-        self.push_stack(f64::NAN);
+        self.push_stack(f64::NAN)?;
         Ok(FrameControl::Continue)
     }
 
     fn op_push_null(&mut self) -> Result<FrameControl<'gc>, Error<'gc>> {
-        self.push_stack(Value::Null);
+        self.push_stack(Value::Null)?;
         Ok(FrameControl::Continue)
     }
 
     fn op_push_short(&mut self, value: i16) -> Result<FrameControl<'gc>, Error<'gc>> {
-        self.push_stack(value);
+        self.push_stack(value)?;
         Ok(FrameControl::Continue)
     }
 
     fn op_push_string(&mut self, string: AvmAtom<'gc>) -> Result<FrameControl<'gc>, Error<'gc>> {
-        self.push_stack(string);
+        self.push_stack(string)?;
         Ok(FrameControl::Continue)
     }
 
     fn op_push_true(&mut self) -> Result<FrameControl<'gc>, Error<'gc>> {
-        self.push_stack(true);
+        self.push_stack(true)?;
         Ok(FrameControl::Continue)
     }
 
     fn op_push_uint(&mut self, value: u32) -> Result<FrameControl<'gc>, Error<'gc>> {
-        self.push_stack(value);
+        self.push_stack(value)?;
         Ok(FrameControl::Continue)
     }
 
     fn op_push_undefined(&mut self) -> Result<FrameControl<'gc>, Error<'gc>> {
-        self.push_stack(Value::Undefined);
+        self.push_stack(Value::Undefined)?;
         Ok(FrameControl::Continue)
     }
 
@@ -1159,13 +1174,13 @@ impl<'a, 'gc> Activation<'a, 'gc> {
                 .last()
                 .cloned()
                 .unwrap_or(Value::Undefined),
-        );
+        )?;
 
         Ok(FrameControl::Continue)
     }
 
     fn op_get_local(&mut self, register_index: u32) -> Result<FrameControl<'gc>, Error<'gc>> {
-        self.push_stack(self.local_register(register_index));
+        self.push_stack(self.local_register(register_index))?;
         Ok(FrameControl::Continue)
     }
 
@@ -1191,7 +1206,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
             .as_callable(self, None, Some(receiver), false)?;
         let value = function.call(receiver, &args, self)?;
 
-        self.push_stack(value);
+        self.push_stack(value)?;
 
         Ok(FrameControl::Continue)
     }
@@ -1215,7 +1230,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         let value = receiver.call_method(index, &args, self)?;
 
         if push_return_value {
-            self.push_stack(value);
+            self.push_stack(value)?;
         }
 
         Ok(FrameControl::Continue)
@@ -1234,7 +1249,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
 
         let value = receiver.call_property(&multiname, &args, self)?;
 
-        self.push_stack(value);
+        self.push_stack(value)?;
 
         Ok(FrameControl::Continue)
     }
@@ -1257,7 +1272,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         )?;
         let value = function.call(Value::Null, &args, self)?;
 
-        self.push_stack(value);
+        self.push_stack(value)?;
 
         Ok(FrameControl::Continue)
     }
@@ -1292,7 +1307,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         let function = FunctionObject::from_method(self, method, scope, None, None);
         let value = function.call(receiver, &args, self)?;
 
-        self.push_stack(value);
+        self.push_stack(value)?;
 
         Ok(FrameControl::Continue)
     }
@@ -1312,7 +1327,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
 
         let value = superclass_object.call_super(&multiname, receiver, &args, self)?;
 
-        self.push_stack(value);
+        self.push_stack(value)?;
 
         Ok(FrameControl::Continue)
     }
@@ -1370,7 +1385,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
             let object = self.pop_stack();
             let object = object.coerce_to_object_or_typeerror(self, Some(&multiname))?;
             let value = object.get_property(&multiname, self)?;
-            self.push_stack(value);
+            self.push_stack(value)?;
             return Ok(FrameControl::Continue);
         }
 
@@ -1389,7 +1404,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
                         if let Some(value) = object.get_index_property(name_int as usize) {
                             let _ = self.pop_stack();
                             let _ = self.pop_stack();
-                            self.push_stack(value);
+                            self.push_stack(value)?;
 
                             return Ok(FrameControl::Continue);
                         }
@@ -1399,7 +1414,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
                             let _ = self.pop_stack();
                             let _ = self.pop_stack();
                             let value = dictionary.get_property_by_object(name_object);
-                            self.push_stack(value);
+                            self.push_stack(value)?;
 
                             return Ok(FrameControl::Continue);
                         }
@@ -1414,7 +1429,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         let object = self.pop_stack();
         let object = object.coerce_to_object_or_typeerror(self, Some(&multiname))?;
         let value = object.get_property(&multiname, self)?;
-        self.push_stack(value);
+        self.push_stack(value)?;
 
         Ok(FrameControl::Continue)
     }
@@ -1544,7 +1559,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
 
         let value = superclass_object.get_super(&multiname, object, self)?;
 
-        self.push_stack(value);
+        self.push_stack(value)?;
 
         Ok(FrameControl::Continue)
     }
@@ -1606,21 +1621,21 @@ impl<'a, 'gc> Activation<'a, 'gc> {
             self.avm2().classes().object.construct(self, &[])?
         };
 
-        self.push_stack(so);
+        self.push_stack(so)?;
 
         Ok(FrameControl::Continue)
     }
 
     fn op_push_scope(&mut self) -> Result<FrameControl<'gc>, Error<'gc>> {
         let object = self.pop_stack().coerce_to_object_or_typeerror(self, None)?;
-        self.push_scope(Scope::new(object));
+        self.push_scope(Scope::new(object))?;
 
         Ok(FrameControl::Continue)
     }
 
     fn op_push_with(&mut self) -> Result<FrameControl<'gc>, Error<'gc>> {
         let object = self.pop_stack().coerce_to_object_or_typeerror(self, None)?;
-        self.push_scope(Scope::new_with(object));
+        self.push_scope(Scope::new_with(object))?;
 
         Ok(FrameControl::Continue)
     }
@@ -1635,7 +1650,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         // Verifier ensures that this points to a valid outer scope
         let scope = self.outer.get_unchecked(index as usize);
 
-        self.push_stack(scope.values());
+        self.push_stack(scope.values())?;
 
         Ok(FrameControl::Continue)
     }
@@ -1644,9 +1659,9 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         let scope = self.scope_frame().get(index as usize).copied();
 
         if let Some(scope) = scope {
-            self.push_stack(scope.values());
+            self.push_stack(scope.values())?;
         } else {
-            self.push_stack(Value::Undefined);
+            self.push_stack(Value::Undefined)?;
         };
 
         Ok(FrameControl::Continue)
@@ -1657,7 +1672,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
             self.global_scope()
                 .map(|gs| gs.into())
                 .unwrap_or(Value::Null),
-        );
+        )?;
 
         Ok(FrameControl::Continue)
     }
@@ -1671,7 +1686,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         avm_debug!(self.avm2(), "Resolving {:?}", *multiname);
         let (_, mut script) = self.domain().find_defining_script(self, &multiname)?;
         let obj = script.globals(&mut self.context)?;
-        self.push_stack(obj);
+        self.push_stack(obj)?;
         Ok(FrameControl::Continue)
     }
 
@@ -1686,7 +1701,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
             .find_definition(&multiname)?
             .or_else(|| self.global_scope());
 
-        self.push_stack(result.map(|o| o.into()).unwrap_or(Value::Undefined));
+        self.push_stack(result.map(|o| o.into()).unwrap_or(Value::Undefined))?;
 
         Ok(FrameControl::Continue)
     }
@@ -1704,7 +1719,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
             });
         let result: Value<'gc> = found?.into();
 
-        self.push_stack(result);
+        self.push_stack(result)?;
 
         Ok(FrameControl::Continue)
     }
@@ -1716,7 +1731,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         let multiname = multiname.fill_with_runtime_params(self)?;
         let object = self.pop_stack().coerce_to_object_or_typeerror(self, None)?;
         if let Some(descendants) = object.xml_descendants(self, &multiname) {
-            self.push_stack(descendants);
+            self.push_stack(descendants)?;
         } else {
             // Even if it's an object with the "descendants" property, we won't support it.
             let class_name = object
@@ -1753,7 +1768,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
                 make_reference_error(self, ReferenceErrorCode::InvalidLookup, &multiname, None)
             });
 
-        self.push_stack(found?);
+        self.push_stack(found?)?;
 
         Ok(FrameControl::Continue)
     }
@@ -1762,7 +1777,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         let object = self.pop_stack().coerce_to_object_or_typeerror(self, None)?;
         let value = object.get_slot(index)?;
 
-        self.push_stack(value);
+        self.push_stack(value)?;
 
         Ok(FrameControl::Continue)
     }
@@ -1792,7 +1807,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
             .transpose()?
             .unwrap_or(Value::Undefined);
 
-        self.push_stack(value);
+        self.push_stack(value)?;
 
         Ok(FrameControl::Continue)
     }
@@ -1813,7 +1828,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
 
         let object = ctor.construct(self, &args)?;
 
-        self.push_stack(object);
+        self.push_stack(object)?;
 
         Ok(FrameControl::Continue)
     }
@@ -1831,7 +1846,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
 
         let object = source.construct_prop(&multiname, &args, self)?;
 
-        self.push_stack(object);
+        self.push_stack(object)?;
 
         Ok(FrameControl::Continue)
     }
@@ -1851,7 +1866,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
             .expect("Activation class should exist for bytecode")
             .construct(self, &[])?;
 
-        self.push_stack(instance);
+        self.push_stack(instance)?;
 
         Ok(FrameControl::Continue)
     }
@@ -1866,7 +1881,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
             object.set_public_property(name.coerce_to_string(self)?, value, self)?;
         }
 
-        self.push_stack(object);
+        self.push_stack(object)?;
 
         Ok(FrameControl::Continue)
     }
@@ -1881,7 +1896,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
 
         let new_fn = FunctionObject::from_function(self, method_entry, scope)?;
 
-        self.push_stack(new_fn);
+        self.push_stack(new_fn)?;
 
         Ok(FrameControl::Continue)
     }
@@ -1919,7 +1934,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
 
         let applied = base.apply(self, &args)?;
 
-        self.push_stack(applied);
+        self.push_stack(applied)?;
 
         Ok(FrameControl::Continue)
     }
@@ -1929,7 +1944,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         let array = ArrayStorage::from_args(&args[..]);
         let array_obj = ArrayObject::from_storage(self, array)?;
 
-        self.push_stack(array_obj);
+        self.push_stack(array_obj)?;
 
         Ok(FrameControl::Continue)
     }
@@ -1966,7 +1981,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
             _ => value,
         };
 
-        self.push_stack(coerced);
+        self.push_stack(coerced)?;
 
         Ok(FrameControl::Continue)
     }
@@ -1997,7 +2012,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         if matches!(value, Value::Null | Value::Undefined) {
             return Err(make_null_or_undefined_error(self, value, None));
         }
-        self.push_stack(value);
+        self.push_stack(value)?;
 
         Ok(FrameControl::Continue)
     }
@@ -2017,7 +2032,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
 
         if value.is_of_type(xml, &mut self.context) || value.is_of_type(xml_list, &mut self.context)
         {
-            self.push_stack(value);
+            self.push_stack(value)?;
         } else {
             return Err(Error::AvmError(type_error(
                 self,
@@ -2038,7 +2053,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         let sum_value = match (value1, value2) {
             // note: with not-yet-guaranteed assumption that Integer < 1<<28, this won't overflow.
             (Value::Integer(n1), Value::Integer(n2)) => (n1 + n2).into(),
-            (Value::Number(n1), Value::Number(n2)) => (n1 + n2).into(),
+            (Value::Number(n1), Value::Number(n2)) => arithmetic_result(n1 + n2),
             (Value::String(s), value2) => Value::String(AvmString::concat(
                 self.context.gc_context,
                 s,
@@ -2075,14 +2090,14 @@ impl<'a, 'gc> Activation<'a, 'gc> {
                         value1.coerce_to_string(self)?,
                         s,
                     )),
-                    (value1, value2) => Value::Number(
+                    (value1, value2) => arithmetic_result(
                         value1.coerce_to_number(self)? + value2.coerce_to_number(self)?,
                     ),
                 }
             }
         };
 
-        self.push_stack(sum_value);
+        self.push_stack(sum_value)?;
 
         Ok(FrameControl::Continue)
     }
@@ -2134,7 +2149,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
     fn op_declocal(&mut self, index: u32) -> Result<FrameControl<'gc>, Error<'gc>> {
         let value = self.local_register(index).coerce_to_number(self)?;
 
-        self.set_local_register(index, value - 1.0);
+        self.set_local_register(index, arithmetic_result(value - 1.0));
 
         Ok(FrameControl::Continue)
     }
@@ -2150,7 +2165,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
     fn op_decrement(&mut self) -> Result<FrameControl<'gc>, Error<'gc>> {
         let value = self.pop_stack().coerce_to_number(self)?;
 
-        self.push_raw(value - 1.0);
+        self.push_raw(arithmetic_result(value - 1.0));
 
         Ok(FrameControl::Continue)
     }
@@ -2167,7 +2182,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         let value2 = self.pop_stack().coerce_to_number(self)?;
         let value1 = self.pop_stack().coerce_to_number(self)?;
 
-        self.push_raw(value1 / value2);
+        self.push_raw(arithmetic_result(value1 / value2));
 
         Ok(FrameControl::Continue)
     }
@@ -2175,7 +2190,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
     fn op_inclocal(&mut self, index: u32) -> Result<FrameControl<'gc>, Error<'gc>> {
         let value = self.local_register(index).coerce_to_number(self)?;
 
-        self.set_local_register(index, value + 1.0);
+        self.set_local_register(index, arithmetic_result(value + 1.0));
 
         Ok(FrameControl::Continue)
     }
@@ -2191,7 +2206,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
     fn op_increment(&mut self) -> Result<FrameControl<'gc>, Error<'gc>> {
         let value = self.pop_stack().coerce_to_number(self)?;
 
-        self.push_raw(value + 1.0);
+        self.push_raw(arithmetic_result(value + 1.0));
 
         Ok(FrameControl::Continue)
     }
@@ -2217,7 +2232,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         let value2 = self.pop_stack().coerce_to_number(self)?;
         let value1 = self.pop_stack().coerce_to_number(self)?;
 
-        self.push_raw(value1 % value2);
+        self.push_raw(arithmetic_result(value1 % value2));
 
         Ok(FrameControl::Continue)
     }
@@ -2226,7 +2241,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         let value2 = self.pop_stack().coerce_to_number(self)?;
         let value1 = self.pop_stack().coerce_to_number(self)?;
 
-        self.push_raw(value1 * value2);
+        self.push_raw(arithmetic_result(value1 * value2));
 
         Ok(FrameControl::Continue)
     }
@@ -2243,7 +2258,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
     fn op_negate(&mut self) -> Result<FrameControl<'gc>, Error<'gc>> {
         let value1 = self.pop_stack().coerce_to_number(self)?;
 
-        self.push_raw(-value1);
+        self.push_raw(arithmetic_result(-value1));
 
         Ok(FrameControl::Continue)
     }
@@ -2272,15 +2287,15 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         let sub_value: Value<'gc> = match (value1, value2) {
             // note: with not-yet-guaranteed assumption that Integer < 1<<28, this won't underflow.
             (Value::Integer(n1), Value::Integer(n2)) => (n1 - n2).into(),
-            (Value::Number(n1), Value::Number(n2)) => (n1 - n2).into(),
+            (Value::Number(n1), Value::Number(n2)) => arithmetic_result(n1 - n2),
             _ => {
                 let value2 = value2.coerce_to_number(self)?;
                 let value1 = value1.coerce_to_number(self)?;
-                (value1 - value2).into()
+                arithmetic_result(value1 - value2)
             }
         };
 
-        self.push_stack(sub_value);
+        self.push_stack(sub_value)?;
 
         Ok(FrameControl::Continue)
     }
@@ -2298,8 +2313,8 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         let value2 = self.pop_stack();
         let value1 = self.pop_stack();
 
-        self.push_stack(value2);
-        self.push_stack(value1);
+        self.push_stack(value2)?;
+        self.push_stack(value1)?;
 
         Ok(FrameControl::Continue)
     }
@@ -2604,7 +2619,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
 
         let name = object.get_enumerant_name(cur_index as u32, self)?;
 
-        self.push_stack(name);
+        self.push_stack(name)?;
 
         Ok(FrameControl::Continue)
     }
@@ -2615,7 +2630,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
 
         let value = object.get_enumerant_value(cur_index as u32, self)?;
 
-        self.push_stack(value);
+        self.push_stack(value)?;
 
         Ok(FrameControl::Continue)
     }
@@ -2659,7 +2674,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         let value = self.pop_stack();
 
         if value.is_of_type(self, class) {
-            self.push_stack(value);
+            self.push_stack(value)?;
         } else {
             self.push_raw(Value::Null);
         }
@@ -2685,7 +2700,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
             let value = self.pop_stack();
 
             if value.is_of_type(self, class.inner_class_definition()) {
-                self.push_stack(value);
+                self.push_stack(value)?;
             } else {
                 self.push_raw(Value::Null);
             }
@@ -2828,7 +2843,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         let val = self.pop_stack();
         let x = val.coerce_to_type(self, class)?;
 
-        self.push_stack(x);
+        self.push_stack(x)?;
         Ok(FrameControl::Continue)
     }
 
@@ -72,6 +72,11 @@ impl<'gc> CallStack<'gc> {
     pub fn is_empty(&self) -> bool {
         self.stack.is_empty()
     }
+
+    /// The number of calls currently on the stack.
+    pub fn depth(&self) -> usize {
+        self.stack.len()
+    }
 }
 
 impl<'gc> Default for CallStack<'gc> {
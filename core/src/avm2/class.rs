@@ -2,22 +2,23 @@
 
 use crate::avm2::activation::Activation;
 use crate::avm2::error::make_error_1014;
+use crate::avm2::metadata::Metadata;
 use crate::avm2::method::{Method, NativeMethodImpl};
 use crate::avm2::object::{ClassObject, Object};
 use crate::avm2::script::TranslationUnit;
 use crate::avm2::traits::{Trait, TraitKind};
-use crate::avm2::value::Value;
 use crate::avm2::Error;
 use crate::avm2::Multiname;
 use crate::avm2::Namespace;
 use crate::avm2::QName;
-use crate::context::UpdateContext;
 use bitflags::bitflags;
 use fnv::FnvHashMap;
 use gc_arena::{Collect, GcCell, Mutation};
+use std::cell::Cell;
+use std::collections::HashSet;
 use std::fmt;
 use std::hash::{Hash, Hasher};
-use std::ops::Deref;
+use std::ops::{ControlFlow, Deref};
 use swf::avm2::types::{
     Class as AbcClass, Instance as AbcInstance, Method as AbcMethod, MethodBody as AbcMethodBody,
 };
@@ -72,6 +73,138 @@ impl fmt::Debug for Allocator {
     }
 }
 
+/// Look up the entry for a playerglobal class in one of the native tables.
+///
+/// If the table is too short (e.g. because it was generated for a different
+/// playerglobal), this warns and returns `None` rather than panicking.
+fn native_table_entry<T: Copy>(
+    table: &[Option<T>],
+    class_index: u32,
+    table_name: &str,
+) -> Option<T> {
+    match table.get(class_index as usize) {
+        Some(entry) => *entry,
+        None => {
+            tracing::warn!(
+                "Class index {class_index} is out of range for the native {table_name} table"
+            );
+            None
+        }
+    }
+}
+
+/// Whether two traits are the getter and the setter of one property, which do
+/// not override or conflict with one another.
+fn is_accessor_pair(a: &TraitKind<'_>, b: &TraitKind<'_>) -> bool {
+    matches!(
+        (a, b),
+        (TraitKind::Getter { .. }, TraitKind::Setter { .. })
+            | (TraitKind::Setter { .. }, TraitKind::Getter { .. })
+    )
+}
+
+/// A single problem found while verifying a class against its superclass.
+///
+/// See `Class::collect_verification_issues`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VerifyIssue {
+    /// A trait overrides a trait that is marked final in a superclass.
+    OverridesFinal {
+        trait_name: String,
+        class_name: String,
+        super_trait_name: String,
+        super_class_name: String,
+    },
+
+    /// A trait has the same name as a superclass trait, but is not marked as
+    /// an override.
+    MissingOverride {
+        trait_name: String,
+        class_name: String,
+        super_trait_name: String,
+        super_class_name: String,
+    },
+
+    /// A trait is marked as an override, but there is nothing to override.
+    NothingToOverride {
+        trait_name: String,
+        class_name: String,
+    },
+
+    /// A trait is defined more than once in the same class.
+    ///
+    /// `validate_class` accepts such classes, so only
+    /// `Class::collect_verification_issues` reports this.
+    DuplicateTrait {
+        trait_name: String,
+        class_name: String,
+    },
+
+    /// A trait declares a slot id beyond the number of slots in the class,
+    /// including inherited ones.
+    SlotIdOutOfRange {
+        trait_name: String,
+        class_name: String,
+        slot_id: u32,
+        slot_count: usize,
+    },
+
+    /// An interface extends itself, directly or through other interfaces.
+    InterfaceCycle { class_name: String },
+}
+
+impl fmt::Display for VerifyIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VerifyIssue::OverridesFinal {
+                trait_name,
+                class_name,
+                super_trait_name,
+                super_class_name,
+            } => write!(
+                f,
+                "Trait {trait_name} in class {class_name} overrides final trait {super_trait_name} in class {super_class_name}"
+            ),
+            VerifyIssue::MissingOverride {
+                trait_name,
+                class_name,
+                super_trait_name,
+                super_class_name,
+            } => write!(
+                f,
+                "Trait {trait_name} in class {class_name} has same name as trait {super_trait_name} in class {super_class_name}, but does not override it"
+            ),
+            VerifyIssue::NothingToOverride {
+                trait_name,
+                class_name,
+            } => write!(
+                f,
+                "Trait {trait_name} in class {class_name} marked as override, does not override any other trait"
+            ),
+            VerifyIssue::DuplicateTrait {
+                trait_name,
+                class_name,
+            } => write!(
+                f,
+                "Trait {trait_name} in class {class_name} is defined more than once"
+            ),
+            VerifyIssue::SlotIdOutOfRange {
+                trait_name,
+                class_name,
+                slot_id,
+                slot_count,
+            } => write!(
+                f,
+                "Trait {trait_name} in class {class_name} has slot id {slot_id}, but the class only has {slot_count} slots"
+            ),
+            VerifyIssue::InterfaceCycle { class_name } => write!(
+                f,
+                "Interface {class_name} extends itself through its superinterfaces"
+            ),
+        }
+    }
+}
+
 /// A loaded ABC Class which can be used to construct objects with.
 #[derive(Clone, Collect)]
 #[collect(no_drop)]
@@ -79,8 +212,11 @@ pub struct Class<'gc> {
     /// The name of the class.
     name: QName<'gc>,
 
-    /// The type parameter for this class (only supported for Vector)
-    param: Option<Option<GcCell<'gc, Class<'gc>>>>,
+    /// The type parameters applied to this class, in order, if it is an
+    /// application of a generic class.
+    ///
+    /// A parameter of `None` signifies the parameter `*`.
+    params: Option<Vec<Option<GcCell<'gc, Class<'gc>>>>>,
 
     /// This class's superclass, or None if it has no superclass
     super_class: Option<GcCell<'gc, Class<'gc>>>,
@@ -142,6 +278,9 @@ pub struct Class<'gc> {
     /// If None, a simple coercion is done.
     call_handler: Option<Method<'gc>>,
 
+    /// The metadata declared on this class, such as `[Event]` annotations.
+    metadata: Option<Box<[Metadata<'gc>]>>,
+
     /// Static traits for a given class.
     ///
     /// These are accessed as class object properties.
@@ -150,10 +289,11 @@ pub struct Class<'gc> {
     /// Whether or not this `Class` has loaded its traits or not.
     traits_loaded: bool,
 
-    /// Maps a type parameter to the application of this class with that parameter.
+    /// Maps a list of type parameters to the application of this class with
+    /// those parameters.
     ///
     /// Only applicable if this class is generic.
-    applications: FnvHashMap<Option<ClassKey<'gc>>, GcCell<'gc, Class<'gc>>>,
+    applications: FnvHashMap<Vec<Option<ClassKey<'gc>>>, GcCell<'gc, Class<'gc>>>,
 
     /// Whether or not this is a system-defined class.
     ///
@@ -166,6 +306,10 @@ pub struct Class<'gc> {
     /// However, a swf can run `newclass` multiple times on the same class
     /// to create multiple `ClassObjects`.
     class_objects: Vec<ClassObject<'gc>>,
+
+    /// Whether `primary_class_object` has already warned that this class has
+    /// more than one class object.
+    warned_multiple_class_objects: Cell<bool>,
 }
 
 impl<'gc> core::fmt::Debug for Class<'gc> {
@@ -216,7 +360,7 @@ impl<'gc> Class<'gc> {
             mc,
             Self {
                 name,
-                param: None,
+                params: None,
                 super_class,
                 attributes: ClassAttributes::empty(),
                 protected_namespace: None,
@@ -228,70 +372,85 @@ impl<'gc> Class<'gc> {
                 class_init,
                 class_initializer_called: false,
                 call_handler: None,
+                metadata: None,
                 class_traits: Vec::new(),
                 traits_loaded: true,
                 is_system: true,
                 applications: FnvHashMap::default(),
                 class_objects: Vec::new(),
+                warned_multiple_class_objects: Cell::new(false),
             },
         )
     }
 
+    fn application_key(params: &[Option<GcCell<'gc, Class<'gc>>>]) -> Vec<Option<ClassKey<'gc>>> {
+        params.iter().map(|param| param.map(ClassKey)).collect()
+    }
+
     pub fn add_application(
         &mut self,
-        param: Option<GcCell<'gc, Class<'gc>>>,
+        params: &[Option<GcCell<'gc, Class<'gc>>>],
         cls: GcCell<'gc, Class<'gc>>,
     ) {
-        let key = param.map(ClassKey);
-        self.applications.insert(key, cls);
+        self.applications.insert(Self::application_key(params), cls);
     }
 
-    /// Apply type parameters to an existing class.
+    /// Apply a single type parameter to an existing class.
     ///
-    /// This is used to parameterize a generic type. The returned class will no
-    /// longer be generic.
+    /// This is used to parameterize a generic type, such as `Vector`. The
+    /// returned class will no longer be generic.
     pub fn with_type_param(
-        context: &mut UpdateContext<'_, 'gc>,
+        mc: &Mutation<'gc>,
         this: GcCell<'gc, Class<'gc>>,
         param: Option<GcCell<'gc, Class<'gc>>>,
     ) -> GcCell<'gc, Class<'gc>> {
-        let mc = context.gc_context;
+        Self::with_type_params(mc, this, &[param])
+    }
 
+    /// Apply an ordered list of type parameters to an existing class.
+    ///
+    /// The returned class will no longer be generic. Applications that the
+    /// class does not already know about are derived from its application of
+    /// `*` for every parameter, which must have been registered with
+    /// `add_application`.
+    pub fn with_type_params(
+        mc: &Mutation<'gc>,
+        this: GcCell<'gc, Class<'gc>>,
+        params: &[Option<GcCell<'gc, Class<'gc>>>],
+    ) -> GcCell<'gc, Class<'gc>> {
         let read = this.read();
-        let key = param.map(ClassKey);
+        let key = Self::application_key(params);
 
         if let Some(application) = read.applications.get(&key) {
             return *application;
         }
 
-        // This can only happen for non-builtin Vector types,
-        // so let's create one here directly.
+        // This can only happen for non-builtin applications, such as
+        // `Vector.<MyType>`, so let's create one here directly.
 
-        let object_vector_cls = read
+        let any_cls = *read
             .applications
-            .get(&None)
-            .expect("Vector.<*> not initialized?");
-
-        let param = param.expect("Trying to create Vector<*>, which shouldn't happen here");
-        let name = format!("Vector.<{}>", param.read().name().to_qualified_name(mc));
+            .get(&vec![None; params.len()])
+            .unwrap_or_else(|| panic!("{}.<*> not initialized?", read.name.local_name()));
+
+        let param_names: Vec<String> = params
+            .iter()
+            .map(|param| match param {
+                Some(param) => param.read().name().to_qualified_name(mc).to_string(),
+                None => "*".to_string(),
+            })
+            .collect();
+        let name = format!("{}.<{}>", read.name.local_name(), param_names.join(","));
 
         let new_class = Self::new(
-            // FIXME - we should store a `Multiname` instead of a `QName`, and use the
-            // `params` field. For now, this is good enough to get tests passing
             QName::new(read.name.namespace(), AvmString::new_utf8(mc, name)),
-            Some(
-                context
-                    .avm2
-                    .classes()
-                    .object_vector
-                    .inner_class_definition(),
-            ),
-            object_vector_cls.read().instance_init(),
-            object_vector_cls.read().class_init(),
+            Some(any_cls),
+            any_cls.read().instance_init(),
+            any_cls.read().class_init(),
             mc,
         );
-        new_class.write(mc).param = Some(Some(param));
-        new_class.write(mc).call_handler = object_vector_cls.read().call_handler();
+        new_class.write(mc).params = Some(params.to_vec());
+        new_class.write(mc).call_handler = any_cls.read().call_handler();
 
         drop(read);
         this.write(mc).applications.insert(key, new_class);
@@ -311,12 +470,62 @@ impl<'gc> Class<'gc> {
         &self.class_objects
     }
 
-    pub fn class_object(&self) -> Option<ClassObject<'gc>> {
-        if self.class_objects.len() == 1 {
-            Some(self.class_objects[0])
-        } else {
-            None
+    /// The number of class objects that have been created for this class.
+    pub fn class_object_count(&self) -> usize {
+        self.class_objects.len()
+    }
+
+    /// Get the first class object created for this class.
+    ///
+    /// Callers that need "the" class object of a class should use this, so
+    /// that classes with several class objects are handled consistently.
+    /// Content that runs `newclass` more than once for the same class is rare,
+    /// so that case is only logged the first time it's seen for each class.
+    pub fn primary_class_object(&self) -> Option<ClassObject<'gc>> {
+        if self.class_object_count() > 1 && !self.warned_multiple_class_objects.replace(true) {
+            tracing::warn!(
+                "Class {:?} has {} class objects, using the first one",
+                self.name,
+                self.class_object_count()
+            );
         }
+
+        self.class_objects.first().copied()
+    }
+
+    /// Get the class object of this class's superclass.
+    ///
+    /// If the superclass is a player global whose defining script hasn't run
+    /// yet, the script is run first so that its class object exists. Returns
+    /// `None` if there is no superclass, or if its class object doesn't exist
+    /// or is still partially initialized (for example, while its own
+    /// definition is being linked).
+    ///
+    /// This takes `this` rather than `&self` so that no borrow of the class is
+    /// held while the superclass's script runs.
+    pub fn superclass_class_object(
+        this: GcCell<'gc, Self>,
+        activation: &mut Activation<'_, 'gc>,
+    ) -> Option<ClassObject<'gc>> {
+        let super_class = this.read().super_class?;
+
+        if super_class.read().class_object_count() == 0 {
+            let name = Multiname::from(super_class.read().name());
+            let playerglobals = activation.avm2().playerglobals_domain();
+            if let Ok(Some((_, mut script))) = playerglobals.get_defining_script(&name) {
+                if let Err(e) = script.globals(&mut activation.context) {
+                    tracing::error!(
+                        "Error initializing script defining {:?}: {e:?}",
+                        super_class.read().name()
+                    );
+                    return None;
+                }
+            }
+        }
+
+        let super_class = super_class.read();
+        let class_object = super_class.primary_class_object()?;
+        super_class.is_class_initialized().then_some(class_object)
     }
 
     /// Construct a class from a `TranslationUnit` and its class index.
@@ -392,13 +601,18 @@ impl<'gc> Class<'gc> {
         // When loading a class from our playerglobal, grab the corresponding native
         // allocator function from the table (which may be `None`)
         if unit.domain().is_playerglobals_domain(activation.avm2()) {
-            instance_allocator = activation.avm2().native_instance_allocator_table
-                [class_index as usize]
-                .map(|(_name, ptr)| Allocator(ptr));
+            instance_allocator = native_table_entry(
+                activation.avm2().native_instance_allocator_table,
+                class_index,
+                "instance allocator",
+            )
+            .map(|(_name, ptr)| Allocator(ptr));
 
-            if let Some((name, table_native_init)) =
-                activation.avm2().native_instance_init_table[class_index as usize]
-            {
+            if let Some((name, table_native_init)) = native_table_entry(
+                activation.avm2().native_instance_init_table,
+                class_index,
+                "instance init",
+            ) {
                 let method = Method::from_builtin_and_params(
                     table_native_init,
                     name,
@@ -410,9 +624,11 @@ impl<'gc> Class<'gc> {
                 native_instance_init = method;
             }
 
-            if let Some((name, table_native_call_handler)) =
-                activation.avm2().native_call_handler_table[class_index as usize]
-            {
+            if let Some((name, table_native_call_handler)) = native_table_entry(
+                activation.avm2().native_call_handler_table,
+                class_index,
+                "call handler",
+            ) {
                 let method = Method::from_builtin_and_params(
                     table_native_call_handler,
                     name,
@@ -431,7 +647,7 @@ impl<'gc> Class<'gc> {
             activation.context.gc_context,
             Self {
                 name,
-                param: None,
+                params: None,
                 super_class,
                 attributes,
                 protected_namespace,
@@ -443,11 +659,13 @@ impl<'gc> Class<'gc> {
                 class_init,
                 class_initializer_called: false,
                 call_handler: native_call_handler,
+                metadata: None,
                 class_traits: Vec::new(),
                 traits_loaded: false,
                 is_system: false,
                 applications: Default::default(),
                 class_objects: Vec::new(),
+                warned_multiple_class_objects: Cell::new(false),
             },
         ))
     }
@@ -501,17 +719,118 @@ impl<'gc> Class<'gc> {
     /// This should be called at class creation time once the superclass name
     /// has been resolved. It will return Ok for a valid class, and a
     /// VerifyError for any invalid class.
-    pub fn validate_class(&self, superclass: Option<ClassObject<'gc>>) -> Result<(), Error<'gc>> {
+    ///
+    /// This fails on the first issue found; use `collect_verification_issues`
+    /// to gather every issue instead. If `verbose` is set, every trait checked
+    /// and the issue found are logged.
+    pub fn validate_class(
+        &self,
+        superclass: Option<ClassObject<'gc>>,
+        verbose: bool,
+    ) -> Result<(), Error<'gc>> {
+        if verbose {
+            for instance_trait in self.instance_traits.iter() {
+                tracing::debug!(
+                    "Verifying trait {:?} of class {:?}",
+                    instance_trait.name(),
+                    self.name()
+                );
+            }
+        }
+
+        if let ControlFlow::Break(issue) =
+            self.visit_verification_issues(superclass, ControlFlow::Break)
+        {
+            if verbose {
+                tracing::debug!("Class {:?} failed verification: {issue}", self.name());
+            }
+
+            return Err(format!("VerifyError: {issue}").into());
+        }
+
+        Ok(())
+    }
+
+    /// Validate a class against it's resolved superclass, collecting every
+    /// issue found instead of stopping at the first one.
+    ///
+    /// This is intended for diagnostics and conformance reports. An empty
+    /// list means the class is valid. Besides everything `validate_class`
+    /// rejects, this also reports traits that are defined more than once.
+    pub fn collect_verification_issues(
+        &self,
+        superclass: Option<ClassObject<'gc>>,
+    ) -> Vec<VerifyIssue> {
+        let mut issues = Vec::new();
+
         // System classes do not throw verify errors.
         if self.is_system {
-            return Ok(());
+            return issues;
+        }
+
+        for (index, instance_trait) in self.instance_traits.iter().enumerate() {
+            let is_duplicate = self.instance_traits[..index].iter().any(|other| {
+                other.name() == instance_trait.name()
+                    && !is_accessor_pair(other.kind(), instance_trait.kind())
+            });
+
+            if is_duplicate {
+                issues.push(VerifyIssue::DuplicateTrait {
+                    trait_name: instance_trait.name().local_name().to_string(),
+                    class_name: self.name().local_name().to_string(),
+                });
+            }
+        }
+
+        let _: ControlFlow<()> = self.visit_verification_issues(superclass, |issue| {
+            issues.push(issue);
+            ControlFlow::Continue(())
+        });
+
+        issues
+    }
+
+    /// Pass every issue found while validating this class to `on_issue`, in
+    /// the order `validate_class` reports them. Validation stops as soon as
+    /// `on_issue` breaks.
+    fn visit_verification_issues<B>(
+        &self,
+        superclass: Option<ClassObject<'gc>>,
+        mut on_issue: impl FnMut(VerifyIssue) -> ControlFlow<B>,
+    ) -> ControlFlow<B> {
+        // System classes do not throw verify errors.
+        if self.is_system {
+            return ControlFlow::Continue(());
+        }
+
+        // Slot ids are 1-based, and may not point past the last slot of the
+        // class; otherwise a single trait could make us allocate an enormous
+        // slot table.
+        let count_slots =
+            |traits: &[Trait<'gc>]| traits.iter().filter(|t| t.slot_id().is_some()).count();
+        let mut slot_count = count_slots(&self.instance_traits);
+        let mut current_superclass = superclass;
+        while let Some(superclass) = current_superclass {
+            slot_count += count_slots(superclass.inner_class_definition().read().instance_traits());
+            current_superclass = superclass.superclass_object();
+        }
+
+        for instance_trait in self.instance_traits.iter() {
+            if let Some(slot_id) = instance_trait.slot_id() {
+                if slot_id as usize > slot_count {
+                    on_issue(VerifyIssue::SlotIdOutOfRange {
+                        trait_name: instance_trait.name().local_name().to_string(),
+                        class_name: self.name().local_name().to_string(),
+                        slot_id,
+                        slot_count,
+                    })?;
+                }
+            }
         }
 
         if let Some(superclass) = superclass {
             for instance_trait in self.instance_traits.iter() {
-                let is_protected = self.protected_namespace().map_or(false, |prot| {
-                    prot.exact_version_match(instance_trait.name().namespace())
-                });
+                let my_name = instance_trait.name();
 
                 let mut current_superclass = Some(superclass);
                 let mut did_override = false;
@@ -520,39 +839,42 @@ impl<'gc> Class<'gc> {
                     let superclass_def = superclass.inner_class_definition();
                     let read = superclass_def.read();
 
-                    for supertrait in read.instance_traits.iter() {
-                        let super_name = supertrait.name();
-                        let my_name = instance_trait.name();
-
-                        let names_match = super_name.local_name() == my_name.local_name()
-                            && (super_name.namespace().matches_ns(my_name.namespace())
-                                || (is_protected
-                                    && read.protected_namespace().map_or(false, |prot| {
-                                        prot.exact_version_match(super_name.namespace())
-                                    })));
-                        if names_match {
-                            match (supertrait.kind(), instance_trait.kind()) {
-                                //Getter/setter pairs do NOT override one another
-                                (TraitKind::Getter { .. }, TraitKind::Setter { .. }) => continue,
-                                (TraitKind::Setter { .. }, TraitKind::Getter { .. }) => continue,
-                                (_, _) => did_override = true,
-                            }
-
-                            if supertrait.is_final() {
-                                return Err(format!("VerifyError: Trait {} in class {} overrides final trait {} in class {}", instance_trait.name().local_name(), self.name().local_name(), supertrait.name().local_name(), read.name().local_name()).into());
-                            }
-
-                            if !instance_trait.is_override() {
-                                return Err(format!("VerifyError: Trait {} in class {} has same name as trait {} in class {}, but does not override it", instance_trait.name().local_name(), self.name().local_name(), supertrait.name().local_name(), read.name().local_name()).into());
-                            }
-
-                            break;
+                    let supertrait = self
+                        .protected_namespace()
+                        .and_then(|prot| read.resolve_protected_trait(instance_trait, prot))
+                        .or_else(|| {
+                            read.instance_traits.iter().find(|supertrait| {
+                                let super_name = supertrait.name();
+
+                                super_name.local_name() == my_name.local_name()
+                                    && super_name.namespace().matches_ns(my_name.namespace())
+                                    && !is_accessor_pair(supertrait.kind(), instance_trait.kind())
+                            })
+                        });
+
+                    if let Some(supertrait) = supertrait {
+                        did_override = true;
+
+                        if supertrait.is_final() {
+                            on_issue(VerifyIssue::OverridesFinal {
+                                trait_name: my_name.local_name().to_string(),
+                                class_name: self.name().local_name().to_string(),
+                                super_trait_name: supertrait.name().local_name().to_string(),
+                                super_class_name: read.name().local_name().to_string(),
+                            })?;
                         }
-                    }
 
-                    // The superclass is already validated so we don't need to
-                    // check further.
-                    if did_override {
+                        if !instance_trait.is_override() {
+                            on_issue(VerifyIssue::MissingOverride {
+                                trait_name: my_name.local_name().to_string(),
+                                class_name: self.name().local_name().to_string(),
+                                super_trait_name: supertrait.name().local_name().to_string(),
+                                super_class_name: read.name().local_name().to_string(),
+                            })?;
+                        }
+
+                        // The superclass is already validated so we don't need
+                        // to check further.
                         break;
                     }
 
@@ -560,12 +882,122 @@ impl<'gc> Class<'gc> {
                 }
 
                 if instance_trait.is_override() && !did_override {
-                    return Err(format!("VerifyError: Trait {} in class {:?} marked as override, does not override any other trait", instance_trait.name().local_name(), self.name()).into());
+                    on_issue(VerifyIssue::NothingToOverride {
+                        trait_name: instance_trait.name().local_name().to_string(),
+                        class_name: self.name().local_name().to_string(),
+                    })?;
                 }
             }
         }
 
-        Ok(())
+        ControlFlow::Continue(())
+    }
+
+    /// Resolve every interface implemented by this class, including those
+    /// implemented by superclasses and any superinterfaces.
+    ///
+    /// Interfaces are resolved in the domain the class was defined in, and
+    /// those that cannot be resolved there are skipped. A class without a
+    /// class object has no domain yet, and so resolves no interfaces.
+    fn resolve_interfaces(
+        &self,
+        activation: &mut Activation<'_, 'gc>,
+    ) -> Vec<GcCell<'gc, Class<'gc>>> {
+        let Some(domain) = self
+            .class_objects
+            .first()
+            .map(|class_object| class_object.class_scope().domain())
+        else {
+            return Vec::new();
+        };
+
+        let mut interface_names = self.direct_interfaces.clone();
+        let mut current_class = self.super_class;
+        while let Some(class) = current_class {
+            let read = class.read();
+            interface_names.extend_from_slice(read.direct_interfaces());
+            current_class = read.super_class();
+        }
+
+        let mut dedup = HashSet::new();
+        let mut interfaces = Vec::new();
+        while let Some(interface_name) = interface_names.pop() {
+            if let Some(interface) = domain.get_class(&mut activation.context, &interface_name) {
+                if dedup.insert(ClassHashWrapper(interface)) {
+                    interface_names.extend_from_slice(interface.read().direct_interfaces());
+                    interfaces.push(interface);
+                }
+            }
+        }
+
+        interfaces
+    }
+
+    /// Determine if `class` can reach itself by following the interfaces it
+    /// extends, as a malformed ABC file may declare.
+    ///
+    /// `resolve` looks up an interface by name; interfaces that cannot be
+    /// resolved are skipped.
+    pub fn has_interface_cycle(
+        class: GcCell<'gc, Class<'gc>>,
+        mut resolve: impl FnMut(&Multiname<'gc>) -> Option<GcCell<'gc, Class<'gc>>>,
+    ) -> bool {
+        let mut visited = HashSet::new();
+        let mut interface_names = class.read().direct_interfaces().to_vec();
+        while let Some(interface_name) = interface_names.pop() {
+            if let Some(interface) = resolve(&interface_name) {
+                if GcCell::ptr_eq(interface, class) {
+                    return true;
+                }
+
+                if visited.insert(ClassHashWrapper(interface)) {
+                    interface_names.extend_from_slice(interface.read().direct_interfaces());
+                }
+            }
+        }
+
+        false
+    }
+
+    /// Determine if a value of this class can be assigned to a slot or
+    /// variable of type `target` without coercion failing.
+    ///
+    /// This is the case if this class is `target`, a subclass of `target`,
+    /// or (if `target` is an interface) implements `target`. `target` is only
+    /// borrowed in the interface case.
+    pub fn is_assignable_to(
+        this: GcCell<'gc, Class<'gc>>,
+        target: GcCell<'gc, Class<'gc>>,
+        activation: &mut Activation<'_, 'gc>,
+    ) -> bool {
+        if GcCell::ptr_eq(this, target) || this.read().is_subclass_of(target) {
+            return true;
+        }
+
+        if target.read().is_interface() {
+            let interfaces = this.read().resolve_interfaces(activation);
+            return interfaces
+                .into_iter()
+                .any(|interface| GcCell::ptr_eq(interface, target));
+        }
+
+        false
+    }
+
+    /// Determine if `other` is one of this class's superclasses.
+    ///
+    /// A class is not considered a subclass of itself.
+    pub fn is_subclass_of(&self, other: GcCell<'gc, Class<'gc>>) -> bool {
+        let mut current_class = self.super_class;
+        while let Some(class) = current_class {
+            if GcCell::ptr_eq(class, other) {
+                return true;
+            }
+
+            current_class = class.read().super_class();
+        }
+
+        false
     }
 
     pub fn for_activation(
@@ -590,35 +1022,34 @@ impl<'gc> Class<'gc> {
             activation.context.gc_context,
             Self {
                 name: QName::new(activation.avm2().public_namespace_base_version, name),
-                param: None,
+                params: None,
                 super_class: None,
                 attributes: ClassAttributes::empty(),
                 protected_namespace: None,
                 direct_interfaces: Vec::new(),
                 instance_allocator: None,
-                instance_init: Method::from_builtin(
-                    |_, _, _| Ok(Value::Undefined),
+                instance_init: Method::trivial_builtin(
                     "<Activation object constructor>",
                     activation.context.gc_context,
                 ),
-                native_instance_init: Method::from_builtin(
-                    |_, _, _| Ok(Value::Undefined),
+                native_instance_init: Method::trivial_builtin(
                     "<Activation object constructor>",
                     activation.context.gc_context,
                 ),
                 instance_traits: traits,
-                class_init: Method::from_builtin(
-                    |_, _, _| Ok(Value::Undefined),
+                class_init: Method::trivial_builtin(
                     "<Activation object class constructor>",
                     activation.context.gc_context,
                 ),
                 class_initializer_called: false,
                 call_handler: None,
+                metadata: None,
                 class_traits: Vec::new(),
                 traits_loaded: true,
                 is_system: false,
                 applications: Default::default(),
                 class_objects: Vec::new(),
+                warned_multiple_class_objects: Cell::new(false),
             },
         ))
     }
@@ -627,12 +1058,21 @@ impl<'gc> Class<'gc> {
         self.name
     }
 
+    /// The string representation of a class object for this class, as
+    /// returned by its `toString`, e.g. `[class Foo]`.
+    ///
+    /// Specialized `Vector` classes already include their parameter in their
+    /// local name, so they produce e.g. `[class Vector.<int>]`.
+    pub fn as_class_string(&self, mc: &Mutation<'gc>) -> AvmString<'gc> {
+        AvmString::new_utf8(mc, format!("[class {}]", self.name.local_name()))
+    }
+
     pub fn set_name(&mut self, name: QName<'gc>) {
         self.name = name;
     }
 
-    pub fn set_param(&mut self, param: Option<Option<GcCell<'gc, Class<'gc>>>>) {
-        self.param = param;
+    pub fn set_params(&mut self, params: Option<Vec<Option<GcCell<'gc, Class<'gc>>>>>) {
+        self.params = params;
     }
 
     pub fn super_class(&self) -> Option<GcCell<'gc, Class<'gc>>> {
@@ -647,6 +1087,36 @@ impl<'gc> Class<'gc> {
         self.protected_namespace
     }
 
+    /// Find the protected instance trait of this class that a trait of a
+    /// subclass overrides.
+    ///
+    /// Each class has its own protected namespace, so a protected trait and
+    /// its override are in different namespaces. `subclass_trait` must be in
+    /// `subclass_protected_ns`, and matches a trait of this class with the
+    /// same local name in this class's protected namespace. A getter never
+    /// matches a setter, or the other way around. Only traits declared on
+    /// this class are considered.
+    pub fn resolve_protected_trait(
+        &self,
+        subclass_trait: &Trait<'gc>,
+        subclass_protected_ns: Namespace<'gc>,
+    ) -> Option<&Trait<'gc>> {
+        let protected_ns = self.protected_namespace?;
+        let name = subclass_trait.name();
+
+        if !subclass_protected_ns.exact_version_match(name.namespace()) {
+            return None;
+        }
+
+        self.instance_traits.iter().find(|supertrait| {
+            let super_name = supertrait.name();
+
+            super_name.local_name() == name.local_name()
+                && protected_ns.exact_version_match(super_name.namespace())
+                && !is_accessor_pair(supertrait.kind(), subclass_trait.kind())
+        })
+    }
+
     #[inline(never)]
     pub fn define_constant_number_class_traits(
         &mut self,
@@ -767,6 +1237,27 @@ impl<'gc> Class<'gc> {
             ));
         }
     }
+
+    #[inline(never)]
+    pub fn define_builtin_class_methods_with_sig(
+        &mut self,
+        mc: &Mutation<'gc>,
+        namespace: Namespace<'gc>,
+        items: Vec<(
+            &'static str,
+            NativeMethodImpl,
+            Vec<ParamConfig<'gc>>,
+            Multiname<'gc>,
+        )>,
+    ) {
+        for (name, value, params, return_type) in items {
+            self.define_class_trait(Trait::from_method(
+                QName::new(namespace, name),
+                Method::from_builtin_and_params(value, name, params, return_type, false, mc),
+            ));
+        }
+    }
+
     #[inline(never)]
     pub fn define_builtin_instance_properties(
         &mut self,
@@ -853,6 +1344,12 @@ impl<'gc> Class<'gc> {
         self.instance_init
     }
 
+    /// Determine if this class's instance initializer is a no-op builtin, so
+    /// that constructing an instance doesn't need to call it.
+    pub fn has_trivial_instance_init(&self) -> bool {
+        self.instance_init.is_trivial()
+    }
+
     /// Get this class's native-code instance initializer.
     pub fn native_instance_init(&self) -> Method<'gc> {
         self.native_instance_init
@@ -873,6 +1370,16 @@ impl<'gc> Class<'gc> {
         self.call_handler = Some(new_call_handler);
     }
 
+    /// Get the metadata declared on this class.
+    pub fn metadata(&self) -> &[Metadata<'gc>] {
+        self.metadata.as_deref().unwrap_or_default()
+    }
+
+    /// Set the metadata declared on this class.
+    pub fn set_metadata(&mut self, metadata: Option<Box<[Metadata<'gc>]>>) {
+        self.metadata = metadata;
+    }
+
     /// Get this class's call handler.
     pub fn call_handler(&self) -> Option<Method<'gc>> {
         self.call_handler
@@ -931,3 +1438,612 @@ impl<'gc> Hash for ClassHashWrapper<'gc> {
         self.0.as_ptr().hash(state);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Class, ClassAttributes, VerifyIssue};
+    use crate::avm2::api_version::ApiVersion;
+    use crate::avm2::method::Method;
+    use crate::avm2::object::ClassObject;
+    use crate::avm2::script::TranslationUnit;
+    use crate::avm2::test_utils::{new_class, noop, with_avm2, with_gc_context};
+    use crate::avm2::traits::{Trait, TraitAttributes, TraitKind};
+    use crate::avm2::{Activation, Error, Multiname, Namespace, QName};
+    use crate::tag_utils::SwfMovie;
+    use gc_arena::GcCell;
+    use std::sync::Arc;
+    use swf::avm2::types::{AbcFile, ConstantPool, Index, Namespace as AbcNamespace};
+
+    fn find_class<'gc>(
+        classes: &[GcCell<'gc, Class<'gc>>],
+        name: &Multiname<'gc>,
+    ) -> Option<GcCell<'gc, Class<'gc>>> {
+        classes
+            .iter()
+            .copied()
+            .find(|class| Some(class.read().name().local_name()) == name.local_name())
+    }
+
+    #[test]
+    fn native_table_entry() {
+        let table = [Some(1), None, Some(3)];
+
+        assert_eq!(super::native_table_entry(&table, 0, "test"), Some(1));
+        assert_eq!(super::native_table_entry(&table, 1, "test"), None);
+        assert_eq!(super::native_table_entry(&table, 2, "test"), Some(3));
+        assert_eq!(super::native_table_entry(&table, 3, "test"), None);
+        assert_eq!(super::native_table_entry(&table, u32::MAX, "test"), None);
+    }
+
+    #[test]
+    fn collect_verification_issues() {
+        with_avm2(10, |activation| {
+            let mc = activation.context.gc_context;
+            let public = activation.avm2().public_namespace_base_version;
+            let object_class = activation.avm2().classes().object;
+
+            let base = new_class(
+                QName::new(public, "Base"),
+                Some(object_class.inner_class_definition()),
+                mc,
+            );
+            let mut locked = Trait::from_method(
+                QName::new(public, "locked"),
+                Method::from_builtin(noop, "locked", mc),
+            );
+            locked.set_attributes(TraitAttributes::FINAL);
+            base.write(mc).define_instance_trait(locked);
+            base.write(mc).define_instance_trait(Trait::from_method(
+                QName::new(public, "plain"),
+                Method::from_builtin(noop, "plain", mc),
+            ));
+            let base_object = ClassObject::from_class(activation, base, Some(object_class))?;
+
+            let derived = new_class(QName::new(public, "Derived"), Some(base), mc);
+            let mut write = derived.write(mc);
+            write.is_system = false;
+            write.define_instance_trait(
+                Trait::from_method(
+                    QName::new(public, "locked"),
+                    Method::from_builtin(noop, "locked", mc),
+                )
+                .with_override(),
+            );
+            write.define_instance_trait(Trait::from_method(
+                QName::new(public, "plain"),
+                Method::from_builtin(noop, "plain", mc),
+            ));
+            write.define_instance_trait(
+                Trait::from_method(
+                    QName::new(public, "extra"),
+                    Method::from_builtin(noop, "extra", mc),
+                )
+                .with_override(),
+            );
+            let mut far = Trait::from_slot(QName::new(public, "far"), Multiname::any(mc), None);
+            far.set_slot_id(99);
+            write.define_instance_trait(far);
+            for _ in 0..2 {
+                write.define_instance_trait(Trait::from_method(
+                    QName::new(public, "twice"),
+                    Method::from_builtin(noop, "twice", mc),
+                ));
+            }
+            // A getter and setter pair is not a duplicate.
+            write.define_instance_trait(Trait::from_getter(
+                QName::new(public, "size"),
+                Method::from_builtin(noop, "size", mc),
+            ));
+            write.define_instance_trait(Trait::from_setter(
+                QName::new(public, "size"),
+                Method::from_builtin(noop, "size", mc),
+            ));
+            drop(write);
+
+            let issues = derived
+                .read()
+                .collect_verification_issues(Some(base_object));
+            assert_eq!(
+                issues,
+                vec![
+                    VerifyIssue::DuplicateTrait {
+                        trait_name: "twice".to_string(),
+                        class_name: "Derived".to_string(),
+                    },
+                    VerifyIssue::SlotIdOutOfRange {
+                        trait_name: "far".to_string(),
+                        class_name: "Derived".to_string(),
+                        slot_id: 99,
+                        slot_count: 1,
+                    },
+                    VerifyIssue::OverridesFinal {
+                        trait_name: "locked".to_string(),
+                        class_name: "Derived".to_string(),
+                        super_trait_name: "locked".to_string(),
+                        super_class_name: "Base".to_string(),
+                    },
+                    VerifyIssue::MissingOverride {
+                        trait_name: "plain".to_string(),
+                        class_name: "Derived".to_string(),
+                        super_trait_name: "plain".to_string(),
+                        super_class_name: "Base".to_string(),
+                    },
+                    VerifyIssue::NothingToOverride {
+                        trait_name: "extra".to_string(),
+                        class_name: "Derived".to_string(),
+                    },
+                ]
+            );
+
+            // `validate_class` still stops at the first issue, and doesn't
+            // reject duplicate traits.
+            let error = derived
+                .read()
+                .validate_class(Some(base_object), false)
+                .expect_err("Derived should fail verification");
+            assert!(format!("{error:?}").contains(&issues[1].to_string()));
+
+            let repeated = new_class(QName::new(public, "Repeated"), Some(base), mc);
+            let mut write = repeated.write(mc);
+            write.is_system = false;
+            for _ in 0..2 {
+                write.define_instance_trait(Trait::from_method(
+                    QName::new(public, "twice"),
+                    Method::from_builtin(noop, "twice", mc),
+                ));
+            }
+            drop(write);
+
+            assert!(repeated
+                .read()
+                .validate_class(Some(base_object), false)
+                .is_ok());
+            assert_eq!(
+                repeated
+                    .read()
+                    .collect_verification_issues(Some(base_object)),
+                vec![VerifyIssue::DuplicateTrait {
+                    trait_name: "twice".to_string(),
+                    class_name: "Repeated".to_string(),
+                }]
+            );
+
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn is_assignable_to() {
+        with_avm2(10, |activation| {
+            let mc = activation.context.gc_context;
+            let public = activation.avm2().public_namespace_base_version;
+            let object_class = activation.avm2().classes().object;
+            let event_dispatcher = activation.avm2().classes().eventdispatcher;
+            let events = Namespace::package(
+                "flash.events",
+                ApiVersion::AllVersions,
+                &mut activation.borrow_gc(),
+            );
+            let interface_name = Multiname::new(events, "IEventDispatcher");
+            let interface = activation
+                .avm2()
+                .playerglobals_domain()
+                .get_class(&mut activation.context, &interface_name)
+                .expect("IEventDispatcher should be defined");
+
+            let subclass = new_class(
+                QName::new(public, "CustomDispatcher"),
+                Some(event_dispatcher.inner_class_definition()),
+                mc,
+            );
+            ClassObject::from_class(activation, subclass, Some(event_dispatcher))?;
+
+            let implementor = new_class(
+                QName::new(public, "Implementor"),
+                Some(object_class.inner_class_definition()),
+                mc,
+            );
+            implementor.write(mc).implements(interface_name);
+            ClassObject::from_class(activation, implementor, Some(object_class))?;
+
+            let unrelated = new_class(
+                QName::new(public, "Unrelated"),
+                Some(object_class.inner_class_definition()),
+                mc,
+            );
+            ClassObject::from_class(activation, unrelated, Some(object_class))?;
+
+            // A subclass is assignable to its superclasses, and to the
+            // interfaces they implement.
+            assert!(Class::is_assignable_to(subclass, subclass, activation));
+            assert!(Class::is_assignable_to(
+                subclass,
+                event_dispatcher.inner_class_definition(),
+                activation
+            ));
+            assert!(Class::is_assignable_to(
+                subclass,
+                object_class.inner_class_definition(),
+                activation
+            ));
+            assert!(Class::is_assignable_to(subclass, interface, activation));
+            assert!(!Class::is_assignable_to(
+                event_dispatcher.inner_class_definition(),
+                subclass,
+                activation
+            ));
+
+            // A class is assignable to the interfaces it implements directly.
+            assert!(Class::is_assignable_to(implementor, interface, activation));
+
+            // Unrelated classes are only assignable to their common ancestors.
+            assert!(!Class::is_assignable_to(unrelated, subclass, activation));
+            assert!(!Class::is_assignable_to(unrelated, implementor, activation));
+            assert!(!Class::is_assignable_to(unrelated, interface, activation));
+            assert!(!Class::is_assignable_to(subclass, unrelated, activation));
+
+            // A superclass target isn't borrowed, so it can still be checked
+            // against while it's being linked.
+            let linking = subclass.write(mc);
+            assert!(Class::is_assignable_to(subclass, subclass, activation));
+            drop(linking);
+            let linking = event_dispatcher.inner_class_definition().write(mc);
+            assert!(Class::is_assignable_to(
+                subclass,
+                event_dispatcher.inner_class_definition(),
+                activation
+            ));
+            drop(linking);
+
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn interface_cycle() {
+        with_gc_context(|context, public| {
+            let mc = context.gc_context;
+
+            let interface = |name: &'static str, extends: &'static str| {
+                let class = new_class(QName::new(public, name), None, mc);
+                let mut write = class.write(mc);
+                write.set_attributes(ClassAttributes::INTERFACE);
+                write.implements(Multiname::new(public, extends));
+                drop(write);
+                class
+            };
+
+            let a = interface("IA", "IB");
+            let b = interface("IB", "IA");
+            let c = interface("IC", "IA");
+            let classes = [a, b, c];
+
+            assert!(Class::has_interface_cycle(a, |name| find_class(
+                &classes, name
+            )));
+            assert!(Class::has_interface_cycle(b, |name| find_class(
+                &classes, name
+            )));
+            // `IC` extends the cycle without being part of it.
+            assert!(!Class::has_interface_cycle(c, |name| find_class(
+                &classes, name
+            )));
+        });
+    }
+
+    #[test]
+    fn with_type_params() {
+        with_gc_context(|context, public| {
+            let mc = context.gc_context;
+            let vec_ns = Namespace::package("__AS3__.vec", ApiVersion::AllVersions, context);
+
+            let int = new_class(QName::new(public, "int"), None, mc);
+            let vector = new_class(QName::new(vec_ns, "Vector"), None, mc);
+            let object_vector = new_class(QName::new(vec_ns, "Vector.<*>"), None, mc);
+            vector.write(mc).add_application(&[None], object_vector);
+
+            assert!(GcCell::ptr_eq(
+                Class::with_type_param(mc, vector, None),
+                object_vector
+            ));
+
+            let int_vector = Class::with_type_param(mc, vector, Some(int));
+            assert_eq!(
+                int_vector.read().name().local_name().to_string(),
+                "Vector.<int>"
+            );
+            assert!(GcCell::ptr_eq(
+                int_vector.read().super_class().unwrap(),
+                object_vector
+            ));
+            assert!(GcCell::ptr_eq(
+                Class::with_type_param(mc, vector, Some(int)),
+                int_vector
+            ));
+
+            let nested = Class::with_type_param(mc, vector, Some(int_vector));
+            assert_eq!(
+                nested.read().name().local_name().to_string(),
+                "Vector.<__AS3__.vec::Vector.<int>>"
+            );
+
+            let key = new_class(QName::new(public, "Key"), None, mc);
+            let map = new_class(QName::new(public, "Map"), None, mc);
+            let any_map = new_class(QName::new(public, "Map.<*,*>"), None, mc);
+            map.write(mc).add_application(&[None, None], any_map);
+
+            let key_int_map = Class::with_type_params(mc, map, &[Some(key), Some(int)]);
+            assert_eq!(
+                key_int_map.read().name().local_name().to_string(),
+                "Map.<Key,int>"
+            );
+            let key_any_map = Class::with_type_params(mc, map, &[Some(key), None]);
+            assert_eq!(
+                key_any_map.read().name().local_name().to_string(),
+                "Map.<Key,*>"
+            );
+            assert!(!GcCell::ptr_eq(key_int_map, key_any_map));
+            assert!(GcCell::ptr_eq(
+                Class::with_type_params(mc, map, &[Some(key), Some(int)]),
+                key_int_map
+            ));
+        });
+    }
+
+    #[test]
+    fn is_subclass_of() {
+        with_gc_context(|context, public| {
+            let mc = context.gc_context;
+
+            let object = new_class(QName::new(public, "Object"), None, mc);
+            let base = new_class(QName::new(public, "Base"), Some(object), mc);
+            let derived = new_class(QName::new(public, "Derived"), Some(base), mc);
+
+            assert!(derived.read().is_subclass_of(base));
+            assert!(derived.read().is_subclass_of(object));
+            assert!(!derived.read().is_subclass_of(derived));
+            assert!(!base.read().is_subclass_of(derived));
+            assert!(!object.read().is_subclass_of(object));
+        });
+    }
+
+    #[test]
+    fn has_trivial_instance_init() {
+        with_gc_context(|context, public| {
+            let mc = context.gc_context;
+
+            let trivial = Class::new(
+                QName::new(public, "Trivial"),
+                None,
+                Method::trivial_builtin("<Trivial instance initializer>", mc),
+                Method::from_builtin(noop, "<Trivial class initializer>", mc),
+                mc,
+            );
+            let real = Class::new(
+                QName::new(public, "Real"),
+                None,
+                Method::from_builtin(noop, "<Real instance initializer>", mc),
+                Method::trivial_builtin("<Real class initializer>", mc),
+                mc,
+            );
+
+            assert!(trivial.read().has_trivial_instance_init());
+            assert!(!real.read().has_trivial_instance_init());
+        });
+    }
+
+    #[test]
+    fn resolve_protected_trait() {
+        with_avm2(10, |activation| {
+            let mc = activation.context.gc_context;
+            let public = activation.avm2().public_namespace_base_version;
+            let object_class = activation.avm2().classes().object;
+            let base_protected = abc_namespace(activation, AbcNamespace::Protected, "Base")?;
+            let derived_protected = abc_namespace(activation, AbcNamespace::Protected, "Derived")?;
+
+            let base = new_class(
+                QName::new(public, "Base"),
+                Some(object_class.inner_class_definition()),
+                mc,
+            );
+            let mut write = base.write(mc);
+            write.protected_namespace = Some(base_protected);
+            write.define_builtin_instance_methods(mc, base_protected, &[("redraw", noop)]);
+            write.define_instance_trait(Trait::from_setter(
+                QName::new(base_protected, "size"),
+                Method::from_builtin(noop, "size", mc),
+            ));
+            write.define_instance_trait(Trait::from_getter(
+                QName::new(base_protected, "size"),
+                Method::from_builtin(noop, "size", mc),
+            ));
+            write.define_instance_trait(Trait::from_method(
+                QName::new(public, "show"),
+                Method::from_builtin(noop, "show", mc),
+            ));
+            drop(write);
+            let base_object = ClassObject::from_class(activation, base, Some(object_class))?;
+
+            let derived_method = |namespace, name: &'static str| {
+                Trait::from_method(
+                    QName::new(namespace, name),
+                    Method::from_builtin(noop, name, mc),
+                )
+            };
+
+            let read = base.read();
+            let resolve = |namespace, name| {
+                read.resolve_protected_trait(&derived_method(namespace, name), derived_protected)
+                    .map(|t| t.name())
+            };
+
+            assert_eq!(
+                resolve(derived_protected, "redraw"),
+                Some(QName::new(base_protected, "redraw"))
+            );
+            // Only traits in the subclass's protected namespace match, and only
+            // against our own protected traits.
+            assert_eq!(resolve(public, "redraw"), None);
+            assert_eq!(resolve(base_protected, "redraw"), None);
+            assert_eq!(resolve(derived_protected, "show"), None);
+            assert_eq!(resolve(derived_protected, "missing"), None);
+
+            // A getter overrides the getter, not the setter declared before it.
+            let getter = Trait::from_getter(
+                QName::new(derived_protected, "size"),
+                Method::from_builtin(noop, "size", mc),
+            );
+            assert!(matches!(
+                read.resolve_protected_trait(&getter, derived_protected)
+                    .map(|t| t.kind()),
+                Some(TraitKind::Getter { .. })
+            ));
+            drop(read);
+
+            let unprotected = new_class(QName::new(public, "Unprotected"), None, mc);
+            assert!(unprotected
+                .read()
+                .resolve_protected_trait(
+                    &derived_method(derived_protected, "redraw"),
+                    derived_protected
+                )
+                .is_none());
+
+            // The verifier uses the same matching for protected overrides.
+            let derived = new_class(QName::new(public, "Derived"), Some(base), mc);
+            let mut write = derived.write(mc);
+            write.is_system = false;
+            write.protected_namespace = Some(derived_protected);
+            write.define_instance_trait(derived_method(derived_protected, "redraw"));
+            drop(write);
+            assert_eq!(
+                derived
+                    .read()
+                    .collect_verification_issues(Some(base_object)),
+                vec![VerifyIssue::MissingOverride {
+                    trait_name: "redraw".to_string(),
+                    class_name: "Derived".to_string(),
+                    super_trait_name: "redraw".to_string(),
+                    super_class_name: "Base".to_string(),
+                }]
+            );
+
+            Ok(())
+        });
+    }
+
+    /// Create a private namespace, which can only be declared in an ABC file.
+    /// Load a namespace of the given kind from a new ABC file. Protected and
+    /// private namespaces can't be created any other way.
+    fn abc_namespace<'gc>(
+        activation: &mut Activation<'_, 'gc>,
+        kind: fn(Index<String>) -> AbcNamespace,
+        name: &str,
+    ) -> Result<Namespace<'gc>, Error<'gc>> {
+        let abc = AbcFile {
+            major_version: 46,
+            minor_version: 16,
+            constant_pool: ConstantPool {
+                ints: vec![],
+                uints: vec![],
+                doubles: vec![],
+                strings: vec![name.as_bytes().to_vec()],
+                namespaces: vec![kind(Index::new(1))],
+                namespace_sets: vec![],
+                multinames: vec![],
+            },
+            methods: vec![],
+            metadata: vec![],
+            instances: vec![],
+            classes: vec![],
+            scripts: vec![],
+            method_bodies: vec![],
+        };
+        let unit = TranslationUnit::from_abc(
+            abc,
+            activation.avm2().stage_domain(),
+            None,
+            Arc::new(SwfMovie::empty(10)),
+            activation.context.gc_context,
+        );
+
+        unit.pool_namespace(Index::new(1), &mut activation.context)
+    }
+
+    #[test]
+    fn class_objects_before_initialization() {
+        with_gc_context(|context, public| {
+            let mc = context.gc_context;
+
+            let class = new_class(QName::new(public, "Uninitialized"), None, mc);
+
+            let read = class.read();
+            assert_eq!(read.class_object_count(), 0);
+            assert!(read.primary_class_object().is_none());
+        });
+    }
+
+    #[test]
+    fn primary_class_object() {
+        with_avm2(10, |activation| {
+            let mc = activation.context.gc_context;
+            let public = activation.avm2().public_namespace_base_version;
+            let object_class = activation.avm2().classes().object;
+
+            let class = new_class(
+                QName::new(public, "Reopened"),
+                Some(object_class.inner_class_definition()),
+                mc,
+            );
+
+            let first = ClassObject::from_class(activation, class, Some(object_class))?;
+            assert_eq!(class.read().class_object_count(), 1);
+            assert_eq!(class.read().primary_class_object(), Some(first));
+            assert!(!class.read().warned_multiple_class_objects.get());
+
+            // As if by running `newclass` twice for the same class.
+            let second = ClassObject::from_class(activation, class, Some(object_class))?;
+            assert_ne!(first, second);
+            assert_eq!(class.read().class_object_count(), 2);
+            assert_eq!(class.read().primary_class_object(), Some(first));
+            assert!(class.read().warned_multiple_class_objects.get());
+            assert_eq!(class.read().primary_class_object(), Some(first));
+
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn superclass_class_object() {
+        with_avm2(10, |activation| {
+            let mc = activation.context.gc_context;
+            let public = activation.avm2().public_namespace_base_version;
+            let utils = Namespace::package(
+                "flash.utils",
+                ApiVersion::AllVersions,
+                &mut activation.borrow_gc(),
+            );
+            let timer = activation
+                .avm2()
+                .playerglobals_domain()
+                .get_class(&mut activation.context, &Multiname::new(utils, "Timer"))
+                .expect("Timer should be defined");
+
+            // Nothing needs `Timer` while the player starts, so its defining
+            // script hasn't run yet.
+            assert_eq!(timer.read().class_object_count(), 0);
+
+            let subclass = new_class(QName::new(public, "CustomTimer"), Some(timer), mc);
+            let superclass_object = Class::superclass_class_object(subclass, activation)
+                .expect("Timer's script should have been run");
+            assert_eq!(timer.read().class_object_count(), 1);
+            assert_eq!(timer.read().primary_class_object(), Some(superclass_object));
+
+            // A class without a superclass has no superclass object.
+            let root = new_class(QName::new(public, "Root"), None, mc);
+            assert!(Class::superclass_class_object(root, activation).is_none());
+
+            Ok(())
+        });
+    }
+}
@@ -223,11 +223,15 @@ impl<'gc> Domain<'gc> {
             if let Some(param) = multiname.param() {
                 if !param.is_any_name() {
                     if let Some(resolved_param) = self.get_class(context, &param) {
-                        return Some(Class::with_type_param(context, class, Some(resolved_param)));
+                        return Some(Class::with_type_param(
+                            context.gc_context,
+                            class,
+                            Some(resolved_param),
+                        ));
                     }
                     return None;
                 } else {
-                    return Some(Class::with_type_param(context, class, None));
+                    return Some(Class::with_type_param(context.gc_context, class, None));
                 }
             }
         }
@@ -263,7 +267,21 @@ impl<'gc> Domain<'gc> {
         activation: &mut Activation<'_, 'gc>,
         name: QName<'gc>,
     ) -> Result<Value<'gc>, Error<'gc>> {
-        let (name, mut script) = self.find_defining_script(activation, &name.into())?;
+        let multiname = name.into();
+        let (name, mut script) = match self.get_defining_script(&multiname)? {
+            Some(definition) => definition,
+            None => {
+                // Only fall back to the embedder's resolver when nothing
+                // defines this name.
+                if let Some(resolver) = activation.avm2().class_resolver() {
+                    if let Some(class) = resolver(activation, &multiname) {
+                        return Ok(class.into());
+                    }
+                }
+
+                self.find_defining_script(activation, &multiname)?
+            }
+        };
         let globals = script.globals(&mut activation.context)?;
 
         globals.get_property(&name.into(), activation)
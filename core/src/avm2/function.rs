@@ -6,7 +6,7 @@ use crate::avm2::object::{ClassObject, Object};
 use crate::avm2::scope::ScopeChain;
 use crate::avm2::traits::TraitKind;
 use crate::avm2::value::Value;
-use crate::avm2::{Error, Multiname};
+use crate::avm2::{Avm2, Error, Multiname};
 use crate::string::WString;
 use gc_arena::{Collect, Gc};
 use std::fmt;
@@ -158,10 +158,7 @@ impl<'gc> Executable<'gc> {
                     resolved_signature,
                     Some(callee),
                 )?;
-                activation
-                    .context
-                    .avm2
-                    .push_call(activation.context.gc_context, self);
+                Avm2::push_call(&mut activation, self)?;
                 method(&mut activation, receiver, &arguments)
             }
             Executable::Action(bm) => {
@@ -196,10 +193,7 @@ impl<'gc> Executable<'gc> {
                     subclass_object,
                     callee,
                 )?;
-                activation
-                    .context
-                    .avm2
-                    .push_call(activation.context.gc_context, self);
+                Avm2::push_call(&mut activation, self)?;
                 activation.run_actions(bm.method)
             }
         };
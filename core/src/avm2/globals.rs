@@ -178,6 +178,16 @@ pub struct SystemClasses<'gc> {
     pub id3info: ClassObject<'gc>,
 }
 
+/// The system classes for the three numeric types.
+///
+/// See `SystemClasses::numeric`.
+#[derive(Clone, Copy)]
+pub struct NumericClasses<'gc> {
+    pub number: ClassObject<'gc>,
+    pub int: ClassObject<'gc>,
+    pub uint: ClassObject<'gc>,
+}
+
 impl<'gc> SystemClasses<'gc> {
     /// Construct a minimal set of system classes necessary for bootstrapping
     /// player globals.
@@ -312,6 +322,15 @@ impl<'gc> SystemClasses<'gc> {
             id3info: object,
         }
     }
+
+    /// Get the `Number`, `int` and `uint` classes together.
+    pub fn numeric(&self) -> NumericClasses<'gc> {
+        NumericClasses {
+            number: self.number,
+            int: self.int,
+            uint: self.uint,
+        }
+    }
 }
 
 /// Looks up a function defined in the script domain, and defines it on the global object.
@@ -375,11 +394,8 @@ fn class<'gc>(
     let mc = activation.context.gc_context;
     let (_, global, mut domain) = script.init();
 
-    let class_read = class_def.read();
-    let super_class = if let Some(super_class) = class_read.super_class() {
-        let super_class = super_class
-            .read()
-            .class_object()
+    let super_class = if class_def.read().super_class().is_some() {
+        let super_class = Class::superclass_class_object(class_def, activation)
             .ok_or_else(|| Error::from("Base class should have been initialized"))?;
 
         Some(super_class)
@@ -387,8 +403,7 @@ fn class<'gc>(
         None
     };
 
-    let class_name = class_read.name();
-    drop(class_read);
+    let class_name = class_def.read().name();
 
     let class_object = ClassObject::from_class(activation, class_def, super_class)?;
     global.install_const_late(
@@ -424,7 +439,7 @@ fn vector_class<'gc>(
     let generic_cls = generic_vector.inner_class_definition();
     generic_cls
         .write(mc)
-        .add_application(cls, vector_cls.inner_class_definition());
+        .add_application(&[cls], vector_cls.inner_class_definition());
 
     let legacy_name = QName::new(activation.avm2().vector_internal_namespace, legacy_name);
     global.install_const_late(
@@ -844,3 +859,23 @@ fn load_playerglobal<'gc>(
         .init_default_domain_memory(activation)?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::avm2::test_utils::with_avm2;
+
+    #[test]
+    fn numeric_classes() {
+        with_avm2(10, |activation| {
+            let classes = activation.avm2().classes();
+            let numeric = classes.numeric();
+
+            assert_eq!(numeric.number, classes.number);
+            assert_eq!(numeric.int, classes.int);
+            assert_eq!(numeric.uint, classes.uint);
+            assert_ne!(numeric.int, numeric.uint);
+
+            Ok(())
+        });
+    }
+}
@@ -7,7 +7,6 @@ use crate::avm2::property::Property;
 use crate::avm2::{ClassObject, Namespace};
 
 use crate::avm2::{Activation, Error, Object, Value};
-use crate::avm2_stub_method;
 
 // Implements `avmplus.describeTypeJSON`
 pub fn describe_type_json<'gc>(
@@ -446,14 +445,17 @@ fn describe_internal_body<'gc>(
     }
 
     if flags.contains(DescribeTypeFlags::INCLUDE_METADATA) {
-        avm2_stub_method!(
-            activation,
-            "avmplus",
-            "describeTypeJSON",
-            "with top-level metadata"
-        );
-
         let metadata_object = ArrayObject::empty(activation)?;
+        // Class-level metadata describes instances, so it is only listed
+        // alongside the instance traits.
+        if use_instance_traits {
+            let class_metadata = class_obj
+                .inner_class_definition()
+                .read()
+                .metadata()
+                .to_vec();
+            write_metadata(metadata_object, &class_metadata, activation)?;
+        }
         traits.set_public_property("metadata", metadata_object.into(), activation)?;
     } else {
         traits.set_public_property("metadata", Value::Null, activation)?;
@@ -2,12 +2,13 @@
 
 use crate::avm2::activation::Activation;
 use crate::avm2::class::{Class, ClassAttributes};
-use crate::avm2::error::{make_error_1002, make_error_1003, make_error_1004};
-use crate::avm2::method::{Method, NativeMethodImpl};
+use crate::avm2::error::{make_error_1002, make_error_1004};
+use crate::avm2::globals::toplevel::{parse_float, parse_int};
+use crate::avm2::method::{Method, NativeMethodImpl, ParamConfig};
 use crate::avm2::object::{primitive_allocator, FunctionObject, Object, TObject};
 use crate::avm2::value::Value;
-use crate::avm2::QName;
 use crate::avm2::{AvmString, Error};
+use crate::avm2::{Multiname, QName};
 use gc_arena::GcCell;
 
 /// Implements `Number`'s instance initializer.
@@ -165,18 +166,39 @@ pub fn to_exponential<'gc>(
         return Err(make_error_1002(activation));
     }
 
-    let digits = digits as usize;
+    if !number.is_finite() {
+        return Ok(Value::Number(number).coerce_to_string(activation)?.into());
+    }
 
     Ok(AvmString::new_utf8(
         activation.context.gc_context,
-        format!("{number:.digits$e}")
-            .replace('e', "e+")
-            .replace("e+-", "e-")
-            .replace("e+0", ""),
+        format_exponential(number, digits as usize),
     )
     .into())
 }
 
+/// Format a finite number in exponential notation, with `digits` digits after
+/// the decimal point, e.g. `1.23e+3`.
+///
+/// Like Flash Player, this leaves out an exponent of zero, so `1.5` is
+/// formatted as `1.5` rather than `1.5e+0`.
+fn format_exponential(number: f64, digits: usize) -> String {
+    let formatted = format!("{number:.digits$e}");
+    let (mantissa, exponent) = formatted
+        .split_once('e')
+        .expect("Exponential formatting should have an exponent");
+    let exponent: i32 = exponent
+        .parse()
+        .expect("Exponential formatting should have an integer exponent");
+    if exponent == 0 {
+        return mantissa.to_string();
+    }
+
+    let sign = if exponent < 0 { '-' } else { '+' };
+
+    format!("{mantissa}e{sign}{}", exponent.unsigned_abs())
+}
+
 /// Implements `Number.toFixed`
 pub fn to_fixed<'gc>(
     activation: &mut Activation<'_, 'gc>,
@@ -195,6 +217,11 @@ pub fn to_fixed<'gc>(
         return Err(make_error_1002(activation));
     }
 
+    // Numbers too large for fixed notation are formatted like `toString`.
+    if number.abs() >= 1e21 {
+        return Ok(Value::Number(number).coerce_to_string(activation)?.into());
+    }
+
     Ok(AvmString::new_utf8(
         activation.context.gc_context,
         format!("{0:.1$}", number, digits as usize),
@@ -277,20 +304,21 @@ pub fn print_with_radix<'gc>(
         }
     }
 
+    const DIGIT_CHARS: [char; 36] = [
+        '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h',
+        'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z',
+    ];
+
     let mut digits = vec![];
     let sign = number.signum();
     number = number.abs();
+    let mut fraction = number.fract();
+    number = number.trunc();
 
     loop {
         let digit = number % radix as f64;
         number /= radix as f64;
 
-        const DIGIT_CHARS: [char; 36] = [
-            '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'a', 'b', 'c', 'd', 'e', 'f', 'g',
-            'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x',
-            'y', 'z',
-        ];
-
         digits.push(*DIGIT_CHARS.get(digit as usize).unwrap());
 
         if number < 1.0 {
@@ -302,7 +330,31 @@ pub fn print_with_radix<'gc>(
         digits.push('-');
     }
 
-    let formatted: String = digits.into_iter().rev().collect();
+    let mut formatted: String = digits.into_iter().rev().collect();
+
+    if fraction > 0.0 {
+        // Digits past the precision of an `f64` would only be noise.
+        let max_digits = (f64::MANTISSA_DIGITS as f64 / (radix as f64).log2()).ceil() as usize;
+
+        formatted.push('.');
+        let mut significant_digits = 0;
+        while fraction != 0.0 && significant_digits < max_digits {
+            fraction *= radix as f64;
+            let digit = fraction.trunc();
+            fraction -= digit;
+
+            // Leading zeros aren't significant, so small numbers still get
+            // their full precision.
+            if digit != 0.0 || significant_digits > 0 {
+                significant_digits += 1;
+            }
+
+            formatted.push(*DIGIT_CHARS.get(digit as usize).unwrap());
+        }
+
+        let trimmed_len = formatted.trim_end_matches('0').trim_end_matches('.').len();
+        formatted.truncate(trimmed_len);
+    }
 
     Ok(AvmString::new_utf8(
         activation.context.gc_context,
@@ -321,10 +373,9 @@ fn to_string<'gc>(
         return Ok("0".into());
     }
 
-    let number = if let Some(this) = this.as_primitive() {
+    let value = if let Some(this) = this.as_primitive() {
         match *this {
-            Value::Integer(o) => o as f64,
-            Value::Number(o) => o,
+            Value::Integer(_) | Value::Number(_) => *this,
             _ => return Err(make_error_1004(activation, "Number.prototype.toString")),
         }
     } else {
@@ -335,13 +386,20 @@ fn to_string<'gc>(
         .get(0)
         .cloned()
         .unwrap_or(Value::Integer(10))
-        .coerce_to_i32(activation)?;
+        .coerce_to_radix(activation)?;
 
-    if radix < 2 || radix > 36 {
-        return Err(make_error_1003(activation, radix));
-    }
+    let number = match value {
+        // Decimal integers print identically to their `f64` counterparts, so
+        // skip the float formatting entirely.
+        Value::Integer(i) if radix == 10 => {
+            return Ok(AvmString::new_utf8(activation.context.gc_context, i.to_string()).into());
+        }
+        Value::Integer(i) => i as f64,
+        Value::Number(n) => n,
+        _ => unreachable!(),
+    };
 
-    Ok(print_with_radix(activation, number, radix as usize)?.into())
+    Ok(print_with_radix(activation, number, radix)?.into())
 }
 
 /// Implements `Number.valueOf`
@@ -432,5 +490,181 @@ pub fn create_class<'gc>(activation: &mut Activation<'_, 'gc>) -> GcCell<'gc, Cl
         AS3_INSTANCE_METHODS,
     );
 
+    // These share their implementation, and signature, with the top-level
+    // `parseFloat` and `parseInt` functions.
+    let public = activation.avm2().public_namespace_base_version;
+    let public_class_methods: Vec<(&str, NativeMethodImpl, _, _)> = vec![
+        (
+            "parseFloat",
+            parse_float,
+            vec![ParamConfig::optional(
+                "number",
+                Multiname::new(public, "String"),
+                "NaN",
+            )],
+            Multiname::new(public, "Number"),
+        ),
+        (
+            "parseInt",
+            parse_int,
+            vec![
+                ParamConfig::optional("string", Multiname::new(public, "String"), "NaN"),
+                ParamConfig::optional("base", Multiname::new(public, "int"), 0),
+            ],
+            Multiname::new(public, "Number"),
+        ),
+    ];
+    write.define_builtin_class_methods_with_sig(mc, public, public_class_methods);
+
     class
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{format_exponential, print_with_radix, to_fixed, to_string};
+    use crate::avm2::test_utils::with_avm2;
+    use crate::avm2::{Activation, Error, Value};
+
+    fn number_to_fixed<'gc>(
+        activation: &mut Activation<'_, 'gc>,
+        number: f64,
+        digits: i32,
+    ) -> Result<String, Error<'gc>> {
+        let this = Value::Number(number).coerce_to_object(activation)?;
+        let formatted = to_fixed(activation, this, &[digits.into()])?;
+
+        Ok(formatted.coerce_to_string(activation)?.to_string())
+    }
+
+    #[test]
+    fn test_format_exponential() {
+        assert_eq!(format_exponential(1234.0, 2), "1.23e+3");
+        assert_eq!(format_exponential(-1234.0, 2), "-1.23e+3");
+        assert_eq!(format_exponential(1234.0, 0), "1e+3");
+        assert_eq!(format_exponential(-0.00012, 0), "-1e-4");
+        assert_eq!(format_exponential(1.5, 1), "1.5");
+        assert_eq!(format_exponential(-1.0, 2), "-1.00");
+        assert_eq!(format_exponential(315003.0, 2), "3.15e+5");
+        assert_eq!(format_exponential(77.1234, 4), "7.7123e+1");
+        assert_eq!(format_exponential(1.2315e19, 2), "1.23e+19");
+        assert_eq!(format_exponential(1.2315e-8, 2), "1.23e-8");
+        assert_eq!(format_exponential(-2.5e-100, 1), "-2.5e-100");
+        assert_eq!(format_exponential(1e21, 0), "1e+21");
+    }
+
+    #[test]
+    fn test_to_fixed() {
+        with_avm2(10, |activation| {
+            assert_eq!(number_to_fixed(activation, 1.005, 2)?, "1.00");
+            assert_eq!(
+                number_to_fixed(activation, 1e20, 2)?,
+                "100000000000000000000.00"
+            );
+            assert_eq!(number_to_fixed(activation, 1e21, 2)?, "1e+21");
+            assert_eq!(number_to_fixed(activation, 1e300, 5)?, "1e+300");
+            assert_eq!(number_to_fixed(activation, -1e300, 5)?, "-1e+300");
+
+            Ok(())
+        })
+    }
+
+    fn radix_string<'gc>(
+        activation: &mut Activation<'_, 'gc>,
+        number: f64,
+        radix: usize,
+    ) -> Result<String, Error<'gc>> {
+        Ok(print_with_radix(activation, number, radix)?.to_string())
+    }
+
+    #[test]
+    fn test_print_with_radix_fractions() {
+        with_avm2(10, |activation| {
+            assert_eq!(radix_string(activation, 3.5, 2)?, "11.1");
+            assert_eq!(radix_string(activation, -2.25, 2)?, "-10.01");
+            assert_eq!(radix_string(activation, 255.5, 16)?, "ff.8");
+            assert_eq!(radix_string(activation, -0.5, 16)?, "-0.8");
+            assert_eq!(radix_string(activation, 1.0 / 3.0, 3)?, "0.1");
+
+            // Fractions that don't terminate in the radix stop once they're
+            // past the precision of an `f64`, at 34 significant digits for
+            // radix 3.
+            let formatted = radix_string(activation, 0.1, 3)?;
+            let (integer, fraction) = formatted.split_once('.').unwrap();
+            assert_eq!(integer, "0");
+            assert!(fraction.starts_with("00220022"));
+            assert!(fraction.trim_start_matches('0').len() <= 34);
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_print_with_radix_small_fractions() {
+        with_avm2(10, |activation| {
+            // Leading zeros don't count towards the precision cap.
+            let zeros = "0".repeat(69);
+            assert_eq!(
+                radix_string(activation, 2f64.powi(-70), 2)?,
+                format!("0.{zeros}1")
+            );
+            let zeros = "0".repeat(17);
+            assert_eq!(
+                radix_string(activation, 2f64.powi(-70), 16)?,
+                format!("0.{zeros}4")
+            );
+            assert_eq!(
+                radix_string(activation, 1e-20, 16)?,
+                "0.00000000000000002f394219248446"
+            );
+            assert_eq!(
+                radix_string(activation, -1e-20, 16)?,
+                "-0.00000000000000002f394219248446"
+            );
+
+            let formatted = radix_string(activation, 1e-20, 2)?;
+            let (integer, fraction) = formatted.split_once('.').unwrap();
+            assert_eq!(integer, "0");
+            let significant = fraction.trim_start_matches('0');
+            assert_eq!(fraction.len() - significant.len(), 66);
+            assert!(significant.starts_with("10111100111001"));
+            assert!(significant.len() <= 53);
+
+            let formatted = radix_string(activation, 1e-10, 2)?;
+            let significant = formatted.trim_start_matches("0.").trim_start_matches('0');
+            assert!(significant.starts_with("11011011111001"));
+            assert!(significant.len() <= 53);
+
+            Ok(())
+        })
+    }
+
+    fn integer_to_string<'gc>(
+        activation: &mut Activation<'_, 'gc>,
+        integer: i32,
+        args: &[Value<'gc>],
+    ) -> Result<String, Error<'gc>> {
+        let this = Value::Integer(integer).coerce_to_object(activation)?;
+        let formatted = to_string(activation, this, args)?;
+
+        Ok(formatted.coerce_to_string(activation)?.to_string())
+    }
+
+    #[test]
+    fn test_to_string_integers() {
+        with_avm2(10, |activation| {
+            assert_eq!(integer_to_string(activation, 0, &[])?, "0");
+            assert_eq!(integer_to_string(activation, i32::MIN, &[])?, "-2147483648");
+            assert_eq!(integer_to_string(activation, i32::MAX, &[])?, "2147483647");
+            assert_eq!(integer_to_string(activation, -255, &[16.into()])?, "-ff");
+
+            // Decimal integers are formatted directly, which must agree with
+            // formatting them as `Number`s.
+            for i in [i32::MIN, i32::MIN + 1, -1, 0, 1, 10, 1000000, i32::MAX] {
+                let number = Value::Number(i as f64).coerce_to_string(activation)?;
+                assert_eq!(integer_to_string(activation, i, &[])?, number.to_string());
+            }
+
+            Ok(())
+        })
+    }
+}
@@ -2,7 +2,7 @@
 
 use crate::avm2::activation::Activation;
 use crate::avm2::class::{Class, ClassAttributes};
-use crate::avm2::error::{make_error_1003, make_error_1004};
+use crate::avm2::error::make_error_1004;
 use crate::avm2::globals::number::print_with_radix;
 use crate::avm2::method::{Method, NativeMethodImpl, ParamConfig};
 use crate::avm2::object::{primitive_allocator, FunctionObject, Object, TObject};
@@ -182,13 +182,9 @@ fn to_string<'gc>(
         .get(0)
         .cloned()
         .unwrap_or(Value::Integer(10))
-        .coerce_to_i32(activation)?;
+        .coerce_to_radix(activation)?;
 
-    if radix < 2 || radix > 36 {
-        return Err(make_error_1003(activation, radix));
-    }
-
-    Ok(print_with_radix(activation, number, radix as usize)?.into())
+    Ok(print_with_radix(activation, number, radix)?.into())
 }
 
 /// Implements `uint.valueOf`
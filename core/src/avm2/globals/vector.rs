@@ -962,7 +962,7 @@ pub fn create_builtin_class<'gc>(
     if param.is_some() {
         write.set_attributes(ClassAttributes::FINAL);
     }
-    write.set_param(Some(param));
+    write.set_params(Some(vec![param]));
     write.set_instance_allocator(vector_allocator);
     write.set_call_handler(Method::from_builtin(
         class_call,
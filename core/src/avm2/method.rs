@@ -350,6 +350,10 @@ pub struct NativeMethod<'gc> {
     /// Whether or not this method accepts parameters beyond those
     /// mentioned in the parameter list.
     pub is_variadic: bool,
+
+    /// Whether this method is known to do nothing, so that calls to it can be
+    /// skipped entirely.
+    pub is_trivial: bool,
 }
 
 impl<'gc> NativeMethod<'gc> {
@@ -412,6 +416,7 @@ impl<'gc> Method<'gc> {
                 resolved_signature: GcCell::new(mc, None),
                 return_type,
                 is_variadic,
+                is_trivial: false,
             },
         ))
     }
@@ -428,6 +433,23 @@ impl<'gc> Method<'gc> {
                 // FIXME - take in the real return type. This is needed for 'describeType'
                 return_type: Multiname::any(mc),
                 is_variadic: true,
+                is_trivial: false,
+            },
+        ))
+    }
+
+    /// Define a builtin that does nothing and returns `undefined`.
+    pub fn trivial_builtin(name: &'static str, mc: &Mutation<'gc>) -> Self {
+        Self::Native(Gc::new(
+            mc,
+            NativeMethod {
+                method: |_, _, _| Ok(Value::Undefined),
+                name,
+                signature: Vec::new(),
+                resolved_signature: GcCell::new(mc, None),
+                return_type: Multiname::any(mc),
+                is_variadic: true,
+                is_trivial: true,
             },
         ))
     }
@@ -465,6 +487,15 @@ impl<'gc> Method<'gc> {
         }
     }
 
+    /// Check if this method was defined with `Method::trivial_builtin`, and so
+    /// does nothing when called.
+    pub fn is_trivial(&self) -> bool {
+        match self {
+            Method::Native(nm) => nm.is_trivial,
+            Method::Bytecode(_) => false,
+        }
+    }
+
     /// Check if this method needs `arguments`.
     pub fn needs_arguments_object(&self) -> bool {
         match self {
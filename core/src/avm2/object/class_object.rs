@@ -1,7 +1,7 @@
 //! Class object impl
 
 use crate::avm2::activation::Activation;
-use crate::avm2::class::{Allocator, AllocatorFn, Class, ClassHashWrapper};
+use crate::avm2::class::{Allocator, AllocatorFn, Class, ClassHashWrapper, VerifyIssue};
 use crate::avm2::error::{argument_error, make_error_1127, reference_error, type_error};
 use crate::avm2::function::Executable;
 use crate::avm2::method::Method;
@@ -248,7 +248,10 @@ impl<'gc> ClassObject<'gc> {
             "Cannot finish initialization of core class without it being linked to a type!",
         )?;
 
-        class.read().validate_class(self.superclass_object())?;
+        let verbose = activation.avm2().verbose_verification();
+        class
+            .read()
+            .validate_class(self.superclass_object(), verbose)?;
 
         self.instance_vtable().init_vtable(
             self,
@@ -332,6 +335,17 @@ impl<'gc> ClassObject<'gc> {
         let class = write.class;
         let scope = write.class_scope;
 
+        if class.read().is_interface()
+            && Class::has_interface_cycle(class, |name| {
+                scope.domain().get_class(&mut activation.context, name)
+            })
+        {
+            let issue = VerifyIssue::InterfaceCycle {
+                class_name: class.read().name().local_name().to_string(),
+            };
+            return Err(format!("VerifyError: {issue}").into());
+        }
+
         let interface_names = class.read().direct_interfaces().to_vec();
         let mut interfaces = Vec::with_capacity(interface_names.len());
 
@@ -825,11 +839,13 @@ impl<'gc> TObject<'gc> for ClassObject<'gc> {
     }
 
     fn to_string(&self, activation: &mut Activation<'_, 'gc>) -> Result<Value<'gc>, Error<'gc>> {
-        Ok(AvmString::new_utf8(
-            activation.context.gc_context,
-            format!("[class {}]", self.0.read().class.read().name().local_name()),
-        )
-        .into())
+        Ok(self
+            .0
+            .read()
+            .class
+            .read()
+            .as_class_string(activation.context.gc_context)
+            .into())
     }
 
     fn to_locale_string(
@@ -879,7 +895,13 @@ impl<'gc> TObject<'gc> for ClassObject<'gc> {
 
         instance.install_instance_slots(activation.context.gc_context);
 
-        self.call_init(instance.into(), arguments, activation)?;
+        if !self
+            .inner_class_definition()
+            .read()
+            .has_trivial_instance_init()
+        {
+            self.call_init(instance.into(), arguments, activation)?;
+        }
 
         Ok(instance)
     }
@@ -966,7 +988,7 @@ impl<'gc> TObject<'gc> for ClassObject<'gc> {
         let class_param = object_param.map(|c| c.inner_class_definition());
 
         let parameterized_class: GcCell<'_, Class<'_>> =
-            Class::with_type_param(&mut activation.context, self_class, class_param);
+            Class::with_type_param(activation.context.gc_context, self_class, class_param);
 
         // NOTE: this isn't fully accurate, but much simpler.
         // FP's Vector is more of special case that literally copies some parent class's properties
@@ -1009,3 +1031,93 @@ impl<'gc> Debug for ClassObject<'gc> {
             .finish()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ClassObject;
+    use crate::avm2::method::Method;
+    use crate::avm2::test_utils::{new_class, noop, with_avm2};
+    use crate::avm2::traits::Trait;
+    use crate::avm2::QName;
+    use std::fmt::Debug;
+    use std::sync::{Arc, Mutex};
+    use tracing::field::Field;
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    /// A subscriber that collects the message of every event logged while it
+    /// is the default.
+    #[derive(Clone, Default)]
+    struct LogCollector(Arc<Mutex<Vec<String>>>);
+
+    impl LogCollector {
+        fn take(&self) -> Vec<String> {
+            std::mem::take(&mut *self.0.lock().unwrap())
+        }
+    }
+
+    impl Subscriber for LogCollector {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, _span: &Attributes<'_>) -> Id {
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut message = String::new();
+            event.record(&mut |field: &Field, value: &dyn Debug| {
+                if field.name() == "message" {
+                    message = format!("{value:?}");
+                }
+            });
+            self.0.lock().unwrap().push(message);
+        }
+
+        fn enter(&self, _span: &Id) {}
+
+        fn exit(&self, _span: &Id) {}
+    }
+
+    #[test]
+    fn verbose_verification_reaches_verifier() {
+        with_avm2(10, |activation| {
+            let mc = activation.context.gc_context;
+            let public = activation.avm2().public_namespace_base_version;
+            let object_class = activation.avm2().classes().object;
+            let logs = LogCollector::default();
+
+            for verbose in [false, true] {
+                activation.avm2().set_verbose_verification(verbose);
+
+                let class = new_class(
+                    QName::new(public, "Verified"),
+                    Some(object_class.inner_class_definition()),
+                    mc,
+                );
+                class.write(mc).define_instance_trait(Trait::from_method(
+                    QName::new(public, "checked"),
+                    Method::from_builtin(noop, "checked", mc),
+                ));
+                tracing::subscriber::with_default(logs.clone(), || {
+                    ClassObject::from_class(activation, class, Some(object_class))
+                })?;
+
+                let verified_trait = logs
+                    .take()
+                    .iter()
+                    .any(|message| message.starts_with("Verifying trait"));
+                assert_eq!(verified_trait, verbose);
+            }
+
+            activation.avm2().set_verbose_verification(false);
+
+            Ok(())
+        });
+    }
+}
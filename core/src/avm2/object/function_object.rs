@@ -34,6 +34,7 @@ pub fn function_allocator<'gc>(
             resolved_signature: GcCell::new(activation.context.gc_context, None),
             return_type: Multiname::any(activation.context.gc_context),
             is_variadic: true,
+            is_trivial: true,
         },
     );
 
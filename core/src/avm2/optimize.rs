@@ -60,7 +60,7 @@ impl<'gc> OptValue<'gc> {
     pub fn of_type_from_class(class: GcCell<'gc, Class<'gc>>) -> Self {
         // FIXME: Getting the ClassObject this way should be unnecessary
         // after the ClassObject refactor
-        if let Some(cls) = class.read().class_object() {
+        if let Some(cls) = class.read().primary_class_object() {
             Self::of_type(cls)
         } else {
             Self::any()
@@ -675,8 +675,11 @@ pub fn optimize<'gc>(
                         *op = Op::Nop;
                     }
                 } else if let Some(class_object) = stack_value.class {
-                    // TODO: this could check for inheritance
-                    if GcCell::ptr_eq(*class, class_object.inner_class_definition()) {
+                    if Class::is_assignable_to(
+                        class_object.inner_class_definition(),
+                        *class,
+                        activation,
+                    ) {
                         *op = Op::Nop;
                     }
                 }
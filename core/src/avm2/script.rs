@@ -9,7 +9,6 @@ use crate::avm2::method::{BytecodeMethod, Method};
 use crate::avm2::object::{Object, TObject};
 use crate::avm2::scope::ScopeChain;
 use crate::avm2::traits::Trait;
-use crate::avm2::value::Value;
 use crate::avm2::Multiname;
 use crate::avm2::Namespace;
 use crate::avm2::{Avm2, Error};
@@ -434,11 +433,7 @@ impl<'gc> Script<'gc> {
             ScriptData {
                 globals,
                 domain,
-                init: Method::from_builtin(
-                    |_, _, _| Ok(Value::Undefined),
-                    "<Built-in script initializer>",
-                    mc,
-                ),
+                init: Method::trivial_builtin("<Built-in script initializer>", mc),
                 traits: Vec::new(),
                 traits_loaded: true,
                 initialized: false,
@@ -0,0 +1,70 @@
+use crate::avm2::api_version::ApiVersion;
+use crate::avm2::class::Class;
+use crate::avm2::method::Method;
+use crate::avm2::object::Object;
+use crate::avm2::{Activation, Error, Namespace, QName, Value};
+use crate::context::GcContext;
+use crate::string::AvmStringInterner;
+use gc_arena::{rootless_arena, GcCell, Mutation};
+
+pub fn with_avm2<F>(swf_version: u8, test: F)
+where
+    F: for<'a, 'gc> FnOnce(&mut Activation<'_, 'gc>) -> Result<(), Error<'gc>>,
+{
+    let movie = crate::tag_utils::SwfMovie::empty(swf_version);
+    let player = crate::player::PlayerBuilder::new()
+        .with_movie(movie)
+        .build();
+    let mut player = player.lock().unwrap();
+    player.mutate_with_update_context(|context| {
+        let mut activation = Activation::from_nothing(context.reborrow());
+        let result = test(&mut activation);
+        if let Err(e) = result {
+            panic!("Encountered exception during test: {e:?}");
+        }
+    })
+}
+
+/// Run a test that only needs a GC arena and a string interner, rather than a
+/// whole player.
+///
+/// The test is also given the public namespace, to name classes and traits in.
+pub fn with_gc_context<F>(test: F)
+where
+    F: for<'gc> FnOnce(&mut GcContext<'_, 'gc>, Namespace<'gc>),
+{
+    rootless_arena(|mc| {
+        let mut interner = AvmStringInterner::new(mc);
+        let mut context = GcContext {
+            gc_context: mc,
+            interner: &mut interner,
+        };
+        let public = Namespace::package("", ApiVersion::AllVersions, &mut context);
+
+        test(&mut context, public);
+    })
+}
+
+/// A native method that does nothing.
+pub fn noop<'gc>(
+    _activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(Value::Undefined)
+}
+
+/// Create a native class whose initializers do nothing.
+pub fn new_class<'gc>(
+    name: QName<'gc>,
+    super_class: Option<GcCell<'gc, Class<'gc>>>,
+    mc: &Mutation<'gc>,
+) -> GcCell<'gc, Class<'gc>> {
+    Class::new(
+        name,
+        super_class,
+        Method::from_builtin(noop, "<instance initializer>", mc),
+        Method::from_builtin(noop, "<class initializer>", mc),
+        mc,
+    )
+}
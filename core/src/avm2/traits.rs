@@ -254,15 +254,26 @@ impl<'gc> Trait<'gc> {
                 },
                 metadata: Metadata::from_abc_index(activation, unit, &abc_trait.metadata)?,
             },
-            AbcTraitKind::Class { slot_id, class } => Trait {
-                name,
-                attributes: trait_attribs_from_abc_traits(abc_trait),
-                kind: TraitKind::Class {
-                    slot_id: *slot_id,
-                    class: unit.load_class(class.0, activation)?,
-                },
-                metadata: Metadata::from_abc_index(activation, unit, &abc_trait.metadata)?,
-            },
+            AbcTraitKind::Class { slot_id, class } => {
+                let class = unit.load_class(class.0, activation)?;
+                let metadata = Metadata::from_abc_index(activation, unit, &abc_trait.metadata)?;
+
+                // Class-level metadata is attached to the trait that declares
+                // the class, so keep a copy on the class itself.
+                class
+                    .write(activation.context.gc_context)
+                    .set_metadata(metadata.clone());
+
+                Trait {
+                    name,
+                    attributes: trait_attribs_from_abc_traits(abc_trait),
+                    kind: TraitKind::Class {
+                        slot_id: *slot_id,
+                        class,
+                    },
+                    metadata,
+                }
+            }
             AbcTraitKind::Function { slot_id, function } => Trait {
                 name,
                 attributes: trait_attribs_from_abc_traits(abc_trait),
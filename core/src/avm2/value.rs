@@ -457,6 +457,68 @@ pub fn string_to_f64(mut s: &WStr, swf_version: u8, strict: bool) -> Option<f64>
     Some(result)
 }
 
+/// Convert a number into a radix in the range 2 to 36, truncating it towards
+/// zero first.
+///
+/// On failure, the truncated radix is returned for use in the error message.
+pub fn radix_from_f64(radix: f64) -> Result<usize, i32> {
+    let radix = f64_to_wrapping_i32(radix);
+
+    if radix < 2 || radix > 36 {
+        return Err(radix);
+    }
+
+    Ok(radix as usize)
+}
+
+/// Minimum exponent after which numbers are formatted as exponential strings.
+const MIN_DIGITS: i32 = -6;
+
+/// Maximum exponent before which numbers are formatted as exponential
+/// strings.
+const MAX_DIGITS: i32 = 21;
+
+/// Maximum number of significant digits rendered for large exponential
+/// numbers.
+///
+/// Any precision beyond this point is truncated. Small exponential numbers
+/// and non-exponential numbers are not limited.
+const MAX_PRECISION: usize = 15;
+
+/// Format a positive, finite, non-zero number the way Flash does when coercing
+/// it to a String.
+///
+/// Digits are generated with the shortest representation that round-trips
+/// back to the same number, which may need up to 17 significant digits.
+fn positive_number_to_string(n: f64) -> String {
+    let exponential = format!("{n:e}");
+    let (mantissa, exponent) = exponential
+        .split_once('e')
+        .expect("exponential format always contains an exponent");
+    let exponent: i32 = exponent.parse().expect("exponent is always an integer");
+
+    if exponent < MIN_DIGITS {
+        format!("{mantissa}e{exponent}")
+    } else if exponent >= MAX_DIGITS {
+        // The mantissa is in the form `d.ddd`, so the decimal point counts as
+        // one extra character.
+        let mantissa = if mantissa.len() > MAX_PRECISION + 1 {
+            &mantissa[..MAX_PRECISION + 1]
+        } else {
+            mantissa
+        };
+        let mantissa = if mantissa.contains('.') {
+            mantissa.trim_end_matches('0').trim_end_matches('.')
+        } else {
+            mantissa
+        };
+
+        format!("{mantissa}e+{exponent}")
+    } else {
+        n.to_string()
+    }
+}
+
 #[allow(clippy::needless_lifetimes)]
 pub fn abc_int<'gc>(
     translation_unit: TranslationUnit<'gc>,
@@ -785,19 +847,20 @@ impl<'gc> Value<'gc> {
         })
     }
 
-    /// Minimum number of digits after which numbers are formatted as
-    /// exponential strings.
-    const MIN_DIGITS: f64 = -6.0;
-
-    /// Maximum number of digits before numbers are formatted as exponential
-    /// strings.
-    const MAX_DIGITS: f64 = 21.0;
-
-    /// Maximum number of significant digits renderable within coerced numbers.
+    /// Coerce the value to a radix, as accepted by the `toString` methods of
+    /// the numeric classes.
     ///
-    /// Any precision beyond this point will be discarded and replaced with
-    /// zeroes (for whole parts) or not rendered (for decimal parts).
-    const MAX_PRECISION: f64 = 15.0;
+    /// Like Flash, the value is first converted with ToInt32 (so fractional
+    /// radixes are truncated towards zero) and only then checked against the
+    /// valid range of 2 to 36. Out-of-range radixes raise error 1003.
+    pub fn coerce_to_radix(
+        &self,
+        activation: &mut Activation<'_, 'gc>,
+    ) -> Result<usize, Error<'gc>> {
+        let radix = self.coerce_to_number(activation)?;
+
+        radix_from_f64(radix).map_err(|radix| error::make_error_1003(activation, radix))
+    }
 
     /// Coerce the value to a String.
     ///
@@ -831,26 +894,7 @@ impl<'gc> Value<'gc> {
             ),
             Value::Number(n) if n.is_infinite() => "Infinity".into(),
             Value::Number(n) => {
-                let digits = n.log10().floor();
-
-                // TODO: This needs to limit precision in the resulting decimal
-                // output, not in binary.
-                let precision = (n * 10.0_f64.powf(Self::MAX_PRECISION - digits)).floor()
-                    / 10.0_f64.powf(Self::MAX_PRECISION - digits);
-
-                if digits < Self::MIN_DIGITS || digits >= Self::MAX_DIGITS {
-                    AvmString::new_utf8(
-                        activation.context.gc_context,
-                        format!(
-                            "{}e{}{}",
-                            precision / 10.0_f64.powf(digits),
-                            if digits < 0.0 { "-" } else { "+" },
-                            digits.abs()
-                        ),
-                    )
-                } else {
-                    AvmString::new_utf8(activation.context.gc_context, n.to_string())
-                }
+                AvmString::new_utf8(activation.context.gc_context, positive_number_to_string(*n))
             }
             Value::Integer(i) => AvmString::new_utf8(activation.context.gc_context, i.to_string()),
             Value::String(s) => *s,
@@ -996,24 +1040,17 @@ impl<'gc> Value<'gc> {
         activation: &mut Activation<'_, 'gc>,
         class: GcCell<'gc, Class<'gc>>,
     ) -> Result<Value<'gc>, Error<'gc>> {
-        if GcCell::ptr_eq(
-            class,
-            activation.avm2().classes().int.inner_class_definition(),
-        ) {
+        let numeric = activation.avm2().classes().numeric();
+
+        if GcCell::ptr_eq(class, numeric.int.inner_class_definition()) {
             return Ok(self.coerce_to_i32(activation)?.into());
         }
 
-        if GcCell::ptr_eq(
-            class,
-            activation.avm2().classes().uint.inner_class_definition(),
-        ) {
+        if GcCell::ptr_eq(class, numeric.uint.inner_class_definition()) {
             return Ok(self.coerce_to_u32(activation)?.into());
         }
 
-        if GcCell::ptr_eq(
-            class,
-            activation.avm2().classes().number.inner_class_definition(),
-        ) {
+        if GcCell::ptr_eq(class, numeric.number.inner_class_definition()) {
             return Ok(self.coerce_to_number(activation)?.into());
         }
 
@@ -1073,6 +1110,23 @@ impl<'gc> Value<'gc> {
         )?))
     }
 
+    /// Convert an integral `Number` to an `Integer`, if it can be one.
+    ///
+    /// Flash Player stores the results of arithmetic as int atoms whenever they
+    /// are integral, so we do the same for the values that the numeric opcodes
+    /// produce. Fractional, non-finite and negative-zero values stay `Number`,
+    /// as do values outside the range that `Value::from(i32)` keeps as
+    /// `Integer`. Non-`Number` values are returned unchanged.
+    #[allow(clippy::float_cmp)]
+    pub fn normalize_numeric(self) -> Value<'gc> {
+        match self {
+            Value::Number(n) if n == (n as i32 as f64) && !(n == 0.0 && n.is_sign_negative()) => {
+                Value::from(n as i32)
+            }
+            value => value,
+        }
+    }
+
     /// Determine if this value is any kind of number.
     pub fn is_number(&self) -> bool {
         match self {
@@ -1319,4 +1373,118 @@ mod tests {
             Some(3.5e20)
         );
     }
+
+    #[test]
+    fn test_string_to_int() {
+        let parse = |s: &[u8], radix| string_to_int(WStr::from_units(s), radix, false);
+
+        assert_eq!(parse(b"0xFF", 16), 255.0);
+        assert_eq!(parse(b"0xFF", 0), 255.0);
+        assert_eq!(parse(b"  -0x1f", 0), -31.0);
+        assert_eq!(parse(b"+42abc", 0), 42.0);
+        assert_eq!(parse(b"\t\n 12", 10), 12.0);
+        assert_eq!(parse(b"z", 36), 35.0);
+        // A "0x" prefix only switches to hexadecimal for radix 0 and 16.
+        assert_eq!(parse(b"0x10", 10), 0.0);
+
+        assert!(parse(b"", 0).is_nan());
+        assert!(parse(b"   ", 0).is_nan());
+        assert!(parse(b"abc", 10).is_nan());
+        assert!(parse(b"-", 0).is_nan());
+        assert!(parse(b"12", 1).is_nan());
+        assert!(parse(b"12", 37).is_nan());
+    }
+
+    #[test]
+    fn test_normalize_numeric() {
+        assert!(matches!(
+            Value::Number(5.0).normalize_numeric(),
+            Value::Integer(5)
+        ));
+        assert!(matches!(
+            Value::Number(-5.0).normalize_numeric(),
+            Value::Integer(-5)
+        ));
+        assert!(matches!(
+            Value::Number(5.5).normalize_numeric(),
+            Value::Number(n) if n == 5.5
+        ));
+        assert!(matches!(
+            Value::Number(3e9).normalize_numeric(),
+            Value::Number(n) if n == 3e9
+        ));
+        assert!(matches!(
+            Value::Number(-0.0).normalize_numeric(),
+            Value::Number(n) if n.is_sign_negative()
+        ));
+        assert!(matches!(
+            Value::Number(f64::NAN).normalize_numeric(),
+            Value::Number(n) if n.is_nan()
+        ));
+        assert!(matches!(
+            Value::Bool(true).normalize_numeric(),
+            Value::Bool(true)
+        ));
+    }
+
+    #[test]
+    fn test_positive_number_to_string() {
+        assert_eq!(positive_number_to_string(0.1 + 0.2), "0.30000000000000004");
+        // This literal has no exact double; the nearest one is printed.
+        assert_eq!(
+            positive_number_to_string(9.999999999999999),
+            "9.999999999999998"
+        );
+        assert_eq!(
+            positive_number_to_string(123.15987654321988),
+            "123.15987654321988"
+        );
+        assert_eq!(
+            positive_number_to_string(1.2315987654321988e-8),
+            "1.2315987654321988e-8"
+        );
+        assert_eq!(positive_number_to_string(1.2315e-6), "0.0000012315");
+        assert_eq!(
+            positive_number_to_string(1.2315e20),
+            "123150000000000000000"
+        );
+        assert_eq!(positive_number_to_string(1.2315e21), "1.2315e+21");
+        assert_eq!(positive_number_to_string(1e21), "1e+21");
+        assert_eq!(positive_number_to_string(1e300), "1e+300");
+        assert_eq!(
+            positive_number_to_string(1.231598765432199e21),
+            "1.23159876543219e+21"
+        );
+        assert_eq!(
+            positive_number_to_string(1.7976931348623157e308),
+            "1.79769313486231e+308"
+        );
+        // Values whose decimal form is commonly surprising.
+        assert_eq!(positive_number_to_string(1.005), "1.005");
+        assert_eq!(positive_number_to_string(0.1), "0.1");
+        assert_eq!(positive_number_to_string(0.1 + 0.7), "0.7999999999999999");
+        assert_eq!(positive_number_to_string(1e-7), "1e-7");
+        assert_eq!(positive_number_to_string(2.5e-7), "2.5e-7");
+        assert_eq!(positive_number_to_string(0.000001), "0.000001");
+        assert_eq!(positive_number_to_string(123.456), "123.456");
+        assert_eq!(positive_number_to_string(1.5), "1.5");
+        assert_eq!(
+            positive_number_to_string(1.0000000000000002),
+            "1.0000000000000002"
+        );
+    }
+
+    #[test]
+    fn test_radix_from_f64() {
+        // `(255).toString(16.9)` truncates to radix 16, giving "ff".
+        assert_eq!(radix_from_f64(16.9), Ok(16));
+        assert_eq!(radix_from_f64(2.0), Ok(2));
+        assert_eq!(radix_from_f64(36.0), Ok(36));
+
+        // `(255).toString(1)` and `(255).toString(37)` raise error 1003.
+        assert_eq!(radix_from_f64(1.0), Err(1));
+        assert_eq!(radix_from_f64(37.0), Err(37));
+        assert_eq!(radix_from_f64(1.9), Err(1));
+        assert_eq!(radix_from_f64(f64::NAN), Err(0));
+    }
 }
@@ -366,9 +366,10 @@ impl<'a, 'gc> UpdateContext<'a, 'gc> {
         *self.instance_counter = 0;
 
         if self.swf.is_action_script_3() {
-            self.avm2.root_api_version =
+            self.avm2.root_api_version = self.avm2.pinned_api_version().unwrap_or_else(|| {
                 ApiVersion::from_swf_version(self.swf.version(), self.avm2.player_runtime)
-                    .unwrap_or_else(|| panic!("Unknown SWF version {}", self.swf.version()));
+                    .unwrap_or_else(|| panic!("Unknown SWF version {}", self.swf.version()))
+            });
         }
 
         self.stage.set_movie_size(
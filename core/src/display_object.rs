@@ -3,8 +3,9 @@ use crate::avm1::{
     Object as Avm1Object, TObject as Avm1TObject, Value as Avm1Value,
 };
 use crate::avm2::{
-    Activation as Avm2Activation, Avm2, Error as Avm2Error, EventObject as Avm2EventObject,
-    Multiname as Avm2Multiname, Object as Avm2Object, TObject as Avm2TObject, Value as Avm2Value,
+    Activation as Avm2Activation, Avm2, BroadcastEvent, Error as Avm2Error,
+    EventObject as Avm2EventObject, Multiname as Avm2Multiname, Object as Avm2Object,
+    TObject as Avm2TObject, Value as Avm2Value,
 };
 use crate::context::{RenderContext, UpdateContext};
 use crate::drawing::Drawing;
@@ -2090,7 +2091,7 @@ pub trait TDisplayObject<'gc>:
     /// may have.
     fn frame_constructed(&self, context: &mut UpdateContext<'_, 'gc>) {
         let frame_constructed_evt =
-            Avm2EventObject::bare_default_event(context, "frameConstructed");
+            Avm2EventObject::bare_default_event(context, BroadcastEvent::FrameConstructed.as_str());
         let dobject_constr = context.avm2.classes().display_object;
         Avm2::broadcast_event(context, frame_constructed_evt, dobject_constr);
     }
@@ -2106,7 +2107,8 @@ pub trait TDisplayObject<'gc>:
 
     /// Emit an `exitFrame` broadcast event.
     fn exit_frame(&self, context: &mut UpdateContext<'_, 'gc>) {
-        let exit_frame_evt = Avm2EventObject::bare_default_event(context, "exitFrame");
+        let exit_frame_evt =
+            Avm2EventObject::bare_default_event(context, BroadcastEvent::ExitFrame.as_str());
         let dobject_constr = context.avm2.classes().display_object;
         Avm2::broadcast_event(context, exit_frame_evt, dobject_constr);
 
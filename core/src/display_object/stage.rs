@@ -3,8 +3,9 @@
 use crate::avm1::Object as Avm1Object;
 use crate::avm2::object::TObject;
 use crate::avm2::{
-    Activation as Avm2Activation, Avm2, EventObject as Avm2EventObject, Object as Avm2Object,
-    ScriptObject as Avm2ScriptObject, StageObject as Avm2StageObject, Value as Avm2Value,
+    Activation as Avm2Activation, Avm2, BroadcastEvent, EventObject as Avm2EventObject,
+    Object as Avm2Object, ScriptObject as Avm2ScriptObject, StageObject as Avm2StageObject,
+    Value as Avm2Value,
 };
 use crate::backend::ui::MouseCursor;
 use crate::config::Letterbox;
@@ -694,7 +695,8 @@ impl<'gc> Stage<'gc> {
     /// TODO: Need additional check as Flash Player does not
     /// broadcast the 'render' event on the first render
     pub fn broadcast_render(&self, context: &mut UpdateContext<'_, 'gc>) {
-        let render_evt = Avm2EventObject::bare_default_event(context, "render");
+        let render_evt =
+            Avm2EventObject::bare_default_event(context, BroadcastEvent::Render.as_str());
         let dobject_constr = context.avm2.classes().display_object;
         Avm2::broadcast_event(context, render_evt, dobject_constr);
 
@@ -856,7 +858,8 @@ impl<'gc> TDisplayObject<'gc> for Stage<'gc> {
             child.enter_frame(context);
         }
 
-        let enter_frame_evt = Avm2EventObject::bare_default_event(context, "enterFrame");
+        let enter_frame_evt =
+            Avm2EventObject::bare_default_event(context, BroadcastEvent::EnterFrame.as_str());
         let dobject_constr = context.avm2.classes().display_object;
         Avm2::broadcast_event(context, enter_frame_evt, dobject_constr);
     }
@@ -6,8 +6,11 @@ use crate::avm1::SystemProperties;
 use crate::avm1::VariableDumper;
 use crate::avm1::{Activation, ActivationIdentifier};
 use crate::avm1::{TObject, Value};
+use crate::avm2::api_version::ApiVersion;
 use crate::avm2::{
-    object::TObject as _, Activation as Avm2Activation, Avm2, CallStack, Object as Avm2Object,
+    object::TObject as _, Activation as Avm2Activation, Avm2, CallStack, ClassObject,
+    ClassResolverFn, Multiname, Object as Avm2Object, DEFAULT_MAX_RECURSION_DEPTH,
+    DEFAULT_MAX_STACK_SIZE,
 };
 use crate::backend::ui::FontDefinition;
 use crate::backend::{
@@ -50,6 +53,7 @@ use crate::timer::Timers;
 use crate::vminterface::Instantiator;
 use crate::DefaultFont;
 use gc_arena::{Collect, DynamicRootSet, GcCell, Rootable};
+use num_traits::FromPrimitive;
 use rand::{rngs::SmallRng, SeedableRng};
 use ruffle_render::backend::{null::NullRenderer, RenderBackend, ViewportDimensions};
 use ruffle_render::commands::CommandList;
@@ -1834,6 +1838,13 @@ impl Player {
         }
     }
 
+    /// Runs a full garbage collection cycle, so that tests can observe
+    /// objects being collected.
+    #[cfg(test)]
+    pub(crate) fn collect_all_garbage(&mut self) {
+        self.gc_arena.borrow_mut().collect_all();
+    }
+
     /// Runs the closure `f` with an `UpdateContext`.
     /// This takes cares of populating the `UpdateContext` struct, avoiding borrow issues.
     pub fn mutate_with_update_context<F, R>(&mut self, f: F) -> R
@@ -2152,6 +2163,7 @@ pub struct PlayerBuilder {
     gamepad_button_mapping: HashMap<GamepadButton, KeyCode>,
     player_version: Option<u8>,
     player_runtime: PlayerRuntime,
+    as_api_version: Option<u16>,
     quality: StageQuality,
     sandbox_type: SandboxType,
     page_url: Option<String>,
@@ -2161,6 +2173,11 @@ pub struct PlayerBuilder {
     #[cfg(feature = "known_stubs")]
     stub_report_output: Option<std::path::PathBuf>,
     avm2_optimizer_enabled: bool,
+    avm2_verbose_verification: bool,
+    avm2_max_stack_size: usize,
+    avm2_max_recursion_depth: usize,
+    avm2_class_resolver: Option<ClassResolverFn>,
+    avm2_broadcast_types: Vec<String>,
 }
 
 impl PlayerBuilder {
@@ -2204,6 +2221,7 @@ impl PlayerBuilder {
             gamepad_button_mapping: HashMap::new(),
             player_version: None,
             player_runtime: PlayerRuntime::default(),
+            as_api_version: None,
             quality: StageQuality::High,
             sandbox_type: SandboxType::LocalTrusted,
             page_url: None,
@@ -2213,6 +2231,11 @@ impl PlayerBuilder {
             #[cfg(feature = "known_stubs")]
             stub_report_output: None,
             avm2_optimizer_enabled: true,
+            avm2_verbose_verification: false,
+            avm2_max_stack_size: DEFAULT_MAX_STACK_SIZE,
+            avm2_max_recursion_depth: DEFAULT_MAX_RECURSION_DEPTH,
+            avm2_class_resolver: None,
+            avm2_broadcast_types: vec![],
         }
     }
 
@@ -2372,6 +2395,22 @@ impl PlayerBuilder {
         self
     }
 
+    /// Pins the ActionScript API version used for the root movie, instead of
+    /// deriving it from the movie's SWF version.
+    ///
+    /// Versions that are not known ActionScript API versions are ignored; use
+    /// `is_valid_as_api_version` to check one beforehand.
+    pub fn with_as_api_version(mut self, version: Option<u16>) -> Self {
+        self.as_api_version = version;
+        self
+    }
+
+    /// Determine if `version` is a known ActionScript API version, which can
+    /// be pinned with `with_as_api_version`.
+    pub fn is_valid_as_api_version(version: u16) -> bool {
+        ApiVersion::from_u16(version).is_some()
+    }
+
     /// Configures the security sandbox type (default is `SandboxType::LocalTrusted`)
     pub fn with_sandbox_type(mut self, sandbox_type: SandboxType) -> Self {
         self.sandbox_type = sandbox_type;
@@ -2420,6 +2459,46 @@ impl PlayerBuilder {
         self
     }
 
+    /// Configures whether AVM2 class verification logs every decision it makes.
+    pub fn with_avm2_verbose_verification(mut self, value: bool) -> Self {
+        self.avm2_verbose_verification = value;
+        self
+    }
+
+    /// Sets how many values the AVM2 operand stack may hold. Lowering this
+    /// bounds the memory that untrusted content can use on it; pushing past
+    /// the limit throws a stack overflow error.
+    pub fn with_avm2_max_stack_size(mut self, max_stack_size: usize) -> Self {
+        self.avm2_max_stack_size = max_stack_size;
+        self
+    }
+
+    /// Sets how deep the AVM2 call stack may get before calls throw a stack
+    /// overflow error. Hosts whose native stack can't fit
+    /// `DEFAULT_MAX_RECURSION_DEPTH` calls should lower this.
+    pub fn with_avm2_max_recursion_depth(mut self, max_recursion_depth: usize) -> Self {
+        self.avm2_max_recursion_depth = max_recursion_depth;
+        self
+    }
+
+    /// Registers a fallback that provides AVM2 classes for names that no
+    /// loaded movie defines, such as synthetic classes supplied by the host.
+    pub fn with_avm2_class_resolver(
+        mut self,
+        resolver: impl for<'gc> Fn(&mut Avm2Activation<'_, 'gc>, &Multiname<'gc>) -> Option<ClassObject<'gc>>
+            + 'static,
+    ) -> Self {
+        self.avm2_class_resolver = Some(Rc::new(resolver));
+        self
+    }
+
+    /// Treats events with the given name as AVM2 broadcast events, which are
+    /// delivered to every listener whether or not it is on the display list.
+    pub fn with_avm2_broadcast_type(mut self, name: impl Into<String>) -> Self {
+        self.avm2_broadcast_types.push(name.into());
+        self
+    }
+
     fn create_gc_root<'gc>(
         gc_context: &'gc gc_arena::Mutation<'gc>,
         player_version: u8,
@@ -2604,6 +2683,21 @@ impl PlayerBuilder {
             context
                 .avm2
                 .set_optimizer_enabled(self.avm2_optimizer_enabled);
+            context.avm2.set_pinned_api_version(self.as_api_version);
+            context
+                .avm2
+                .set_verbose_verification(self.avm2_verbose_verification);
+            context.avm2.set_max_stack_size(self.avm2_max_stack_size);
+            context
+                .avm2
+                .set_max_recursion_depth(self.avm2_max_recursion_depth);
+            context
+                .avm2
+                .set_class_resolver(self.avm2_class_resolver.clone());
+            for name in &self.avm2_broadcast_types {
+                let name = AvmString::new_utf8(context.gc_context, name);
+                context.avm2.register_broadcast_type(name);
+            }
             Avm2::load_player_globals(context).expect("Unable to load AVM2 globals");
 
             let stage = context.stage;
@@ -14,6 +14,7 @@ language = Language
 audio-output-device = Audio Output Device
 audio-output-device-default = System Default
 
+log-enabled = Write Log File
 log-filename-pattern = Log Filename
 log-filename-pattern-single-file = Single File (ruffle.log)
 log-filename-pattern-with-timestamp = With Timestamp
@@ -70,8 +70,10 @@ pub struct Opt {
     pub height: Option<f64>,
 
     /// Maximum number of seconds a script can run before scripting is disabled.
-    #[clap(long, short, default_value = "Infinity")]
-    pub max_execution_duration: f64,
+    /// This option temporarily overrides any stored preference.
+    /// By default, scripts may run for as long as they like.
+    #[clap(long, short)]
+    pub max_execution_duration: Option<f64>,
 
     /// Base directory or URL used to resolve all relative path statements in the SWF file.
     /// The default is the current directory.
@@ -26,6 +26,9 @@ pub struct PreferencesDialog {
     available_output_devices: Vec<String>,
     output_device_changed: bool,
 
+    log_enabled: bool,
+    log_enabled_changed: bool,
+
     log_filename_pattern: FilenamePattern,
     log_filename_pattern_changed: bool,
 
@@ -65,6 +68,9 @@ impl PreferencesDialog {
             available_output_devices,
             output_device_changed: false,
 
+            log_enabled: preferences.log_enabled(),
+            log_enabled_changed: false,
+
             log_filename_pattern: preferences.log_filename_pattern(),
             log_filename_pattern_changed: false,
 
@@ -128,6 +134,7 @@ impl PreferencesDialog {
         self.graphics_backend != self.preferences.graphics_backends()
             || self.power_preference != self.preferences.graphics_power_preference()
             || self.output_device != self.preferences.output_device_name()
+            || self.log_enabled != self.preferences.log_enabled()
             || self.log_filename_pattern != self.preferences.log_filename_pattern()
             || self.storage_backend != self.preferences.storage_backend()
     }
@@ -254,6 +261,12 @@ impl PreferencesDialog {
     }
 
     fn show_log_preferences(&mut self, locale: &LanguageIdentifier, ui: &mut Ui) {
+        ui.label(text(locale, "log-enabled"));
+        if ui.checkbox(&mut self.log_enabled, "").changed() {
+            self.log_enabled_changed = true;
+        }
+        ui.end_row();
+
         ui.label(text(locale, "log-filename-pattern"));
 
         let previous = self.log_filename_pattern;
@@ -328,6 +341,9 @@ impl PreferencesDialog {
                 preferences.set_output_device(self.output_device.clone());
                 // [NA] TODO: Inform the running player that the device changed
             }
+            if self.log_enabled_changed {
+                preferences.set_log_enabled(self.log_enabled);
+            }
             if self.log_filename_pattern_changed {
                 preferences.set_log_filename_pattern(self.log_filename_pattern);
             }
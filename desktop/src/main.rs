@@ -155,10 +155,15 @@ async fn main() -> Result<(), Error> {
 
     // [NA] `_guard` cannot be `_` or it'll immediately drop
     // https://docs.rs/tracing-appender/latest/tracing_appender/non_blocking/index.html
-    let log_path = preferences
-        .log_filename_pattern()
-        .create_path(&preferences.cli.config);
-    let (non_blocking_file, _file_guard) = tracing_appender::non_blocking(File::create(log_path)?);
+    let (non_blocking_file, _file_guard) = if preferences.log_enabled() {
+        let log_path = preferences
+            .log_filename_pattern()
+            .create_path(&preferences.cli.config);
+        let (writer, guard) = tracing_appender::non_blocking(File::create(log_path)?);
+        (Some(writer), Some(guard))
+    } else {
+        (None, None)
+    };
     let (non_blocking_stdout, _stdout_guard) = tracing_appender::non_blocking(std::io::stdout());
 
     let env_filter = tracing_subscriber::EnvFilter::builder().parse_lossy(
@@ -170,7 +175,7 @@ async fn main() -> Result<(), Error> {
     let subscriber = tracing_subscriber::registry()
         .with(env_filter)
         .with(Layer::new().with_writer(non_blocking_stdout))
-        .with(Layer::new().with_writer(non_blocking_file).with_ansi(false));
+        .with(non_blocking_file.map(|file| Layer::new().with_writer(file).with_ansi(false)));
 
     #[cfg(feature = "tracy")]
     let subscriber = {
@@ -56,18 +56,20 @@ pub struct PlayerOptions {
     pub spoof_url: Option<Url>,
     pub player_version: u8,
     pub player_runtime: PlayerRuntime,
+    pub as_api_version: Option<u16>,
     pub frame_rate: Option<f64>,
     pub open_url_mode: OpenURLMode,
     pub dummy_external_interface: bool,
     pub gamepad_button_mapping: HashMap<GamepadButton, KeyCode>,
     pub avm2_optimizer_enabled: bool,
+    pub avm2_verbose_verification: bool,
 }
 
 impl From<&GlobalPreferences> for PlayerOptions {
     fn from(value: &GlobalPreferences) -> Self {
         Self {
             parameters: value.cli.parameters().collect(),
-            max_execution_duration: value.cli.max_execution_duration,
+            max_execution_duration: value.max_execution_duration(),
             base: value.cli.base.clone(),
             quality: value.cli.quality,
             align: value.cli.align.unwrap_or_default(),
@@ -81,8 +83,13 @@ impl From<&GlobalPreferences> for PlayerOptions {
             save_directory: value.cli.save_directory.clone(),
             letterbox: value.cli.letterbox,
             spoof_url: value.cli.spoof_url.clone(),
-            player_version: value.cli.player_version.unwrap_or(32),
+            player_version: value
+                .cli
+                .player_version
+                .or_else(|| value.spoof_player_version().map(|(major, ..)| major))
+                .unwrap_or(32),
             player_runtime: value.cli.player_runtime,
+            as_api_version: value.as_api_version(),
             frame_rate: value.cli.frame_rate,
             open_url_mode: value.cli.open_url_mode,
             dummy_external_interface: value.cli.dummy_external_interface,
@@ -90,6 +97,7 @@ impl From<&GlobalPreferences> for PlayerOptions {
             tcp_connections: value.cli.tcp_connections,
             gamepad_button_mapping: HashMap::from_iter(value.cli.gamepad_button.iter().cloned()),
             avm2_optimizer_enabled: !value.cli.no_avm2_optimizer,
+            avm2_verbose_verification: value.verbose_avm2_verification(),
         }
     }
 }
@@ -207,7 +215,11 @@ impl ActivePlayer {
         builder = builder
             .with_navigator(navigator)
             .with_renderer(renderer)
-            .with_storage(preferences.storage_backend().create_backend(opt))
+            .with_storage(
+                preferences
+                    .storage_backend_for_domain(movie_url.host_str())
+                    .create_backend(opt),
+            )
             .with_fs_commands(Box::new(DesktopFSCommandProvider {
                 event_loop: event_loop.clone(),
                 window: window.clone(),
@@ -233,8 +245,10 @@ impl ActivePlayer {
             .with_page_url(opt.spoof_url.clone().map(|url| url.to_string()))
             .with_player_version(Some(opt.player_version))
             .with_player_runtime(opt.player_runtime)
+            .with_as_api_version(opt.as_api_version)
             .with_frame_rate(opt.frame_rate)
-            .with_avm2_optimizer_enabled(opt.avm2_optimizer_enabled);
+            .with_avm2_optimizer_enabled(opt.avm2_optimizer_enabled)
+            .with_avm2_verbose_verification(opt.avm2_verbose_verification);
         let player = builder.build();
 
         window.set_title(&format!("Ruffle - {readable_name}"));
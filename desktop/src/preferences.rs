@@ -4,14 +4,18 @@ mod write;
 pub mod storage;
 
 use crate::cli::Opt;
-use crate::log::FilenamePattern;
-use crate::preferences::read::read_preferences;
+use crate::log::{FilenamePattern, LogLevel};
+use crate::preferences::read::read_preferences_with_env;
 use crate::preferences::write::PreferencesWriter;
 use anyhow::{Context, Error};
 use ruffle_core::backend::ui::US_ENGLISH;
 use ruffle_frontend_utils::bookmarks::{read_bookmarks, Bookmarks, BookmarksWriter};
 use ruffle_frontend_utils::parse::DocumentHolder;
 use ruffle_render_wgpu::clap::{GraphicsBackend, PowerPreference};
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+use std::str::FromStr;
 use std::sync::{Arc, Mutex};
 use sys_locale::get_locale;
 use unic_langid::LanguageIdentifier;
@@ -45,18 +49,18 @@ impl GlobalPreferences {
     pub fn load(cli: Opt) -> Result<Self, Error> {
         std::fs::create_dir_all(&cli.config).context("Failed to create configuration directory")?;
         let preferences_path = cli.config.join("preferences.toml");
-        let preferences = if preferences_path.exists() {
-            let contents = std::fs::read_to_string(&preferences_path)
-                .context("Failed to read saved preferences")?;
-            let result = read_preferences(&contents);
-            for warning in result.warnings {
-                // TODO: A way to display warnings to users, generally
-                tracing::warn!("{warning}");
-            }
-            result.result
+        let contents = if preferences_path.exists() {
+            std::fs::read_to_string(&preferences_path)
+                .context("Failed to read saved preferences")?
         } else {
-            Default::default()
+            String::new()
         };
+        let result = read_preferences_with_env(&contents, |name| std::env::var(name).ok());
+        for warning in result.warnings {
+            // TODO: A way to display warnings to users, generally
+            tracing::warn!("{warning}");
+        }
+        let preferences = result.result;
 
         let bookmarks_path = cli.config.join("bookmarks.toml");
         let bookmarks = if bookmarks_path.exists() {
@@ -128,6 +132,47 @@ impl GlobalPreferences {
         })
     }
 
+    /// The maximum number of seconds a script can run, or infinity if there
+    /// is no limit.
+    pub fn max_execution_duration(&self) -> f64 {
+        self.cli.max_execution_duration.unwrap_or_else(|| {
+            self.preferences
+                .lock()
+                .expect("Preferences is not reentrant")
+                .max_execution_duration_secs
+                .unwrap_or(f64::INFINITY)
+        })
+    }
+
+    pub fn spoof_player_version(&self) -> Option<(u8, u8, u16, u16)> {
+        self.preferences
+            .lock()
+            .expect("Preferences is not reentrant")
+            .spoof_player_version
+    }
+
+    pub fn as_api_version(&self) -> Option<u16> {
+        self.preferences
+            .lock()
+            .expect("Preferences is not reentrant")
+            .as_api_version
+    }
+
+    pub fn verbose_avm2_verification(&self) -> bool {
+        self.preferences
+            .lock()
+            .expect("Preferences is not reentrant")
+            .verbose_avm2_verification
+    }
+
+    pub fn log_enabled(&self) -> bool {
+        self.preferences
+            .lock()
+            .expect("Preferences is not reentrant")
+            .log
+            .enabled
+    }
+
     pub fn log_filename_pattern(&self) -> FilenamePattern {
         self.preferences
             .lock()
@@ -156,6 +201,23 @@ impl GlobalPreferences {
         })
     }
 
+    /// The storage backend to use for a movie hosted on the given domain.
+    ///
+    /// A per-domain override takes precedence over the global storage backend,
+    /// but the CLI still takes precedence over both.
+    pub fn storage_backend_for_domain(&self, domain: Option<&str>) -> storage::StorageBackend {
+        self.cli.storage.unwrap_or_else(|| {
+            let preferences = self
+                .preferences
+                .lock()
+                .expect("Preferences is not reentrant");
+            domain
+                .and_then(|domain| preferences.storage.per_domain_overrides.get(domain))
+                .copied()
+                .unwrap_or(preferences.storage.backend)
+        })
+    }
+
     pub fn write_preferences(&self, fun: impl FnOnce(&mut PreferencesWriter)) -> Result<(), Error> {
         let mut preferences = self
             .preferences
@@ -186,10 +248,118 @@ impl GlobalPreferences {
 pub struct SavedGlobalPreferences {
     pub graphics_backend: GraphicsBackend,
     pub graphics_power_preference: PowerPreference,
+
+    /// The highest graphics feature level to request, one of
+    /// `MAX_FEATURE_LEVELS`. `None` requests whatever the device supports.
+    pub max_feature_level: Option<String>,
     pub language: LanguageIdentifier,
+
+    /// The keyboard layout, one of `KEYBOARD_LAYOUTS`, that content is
+    /// assumed to be written for. `None` uses the system layout.
+    pub keyboard_layout: Option<String>,
+
     pub output_device: Option<String>,
     pub mute: bool,
     pub volume: f32,
+
+    /// How many channels audio should be mixed down to.
+    pub audio_channels: AudioChannels,
+
+    /// The Flash Player version to report to content, as
+    /// `(major, minor, build, revision)`, instead of our own.
+    pub spoof_player_version: Option<(u8, u8, u16, u16)>,
+
+    /// The ActionScript API version to use when resolving versioned public
+    /// namespaces, instead of the one derived from the movie's SWF version.
+    pub as_api_version: Option<u16>,
+
+    /// Remembered zoom factors for specific movies, keyed by path.
+    pub movie_zoom: HashMap<String, f32>,
+
+    /// The entry to launch when opening an archive that contains multiple
+    /// movies, keyed by the path of the archive.
+    pub default_main_swf: HashMap<String, String>,
+
+    /// How long the mouse cursor may be inactive before it is hidden during
+    /// playback. `Some(0)` hides it immediately; `None` never hides it.
+    pub hide_cursor_after_ms: Option<u32>,
+
+    /// How many seconds a script may run before it is timed out.
+    /// `None` disables the timeout.
+    pub max_execution_duration_secs: Option<f64>,
+
+    /// The most memory that loaded content may use, in megabytes.
+    /// `None` means there is no limit.
+    pub max_content_memory_mb: Option<u32>,
+
+    /// The largest width or height, in pixels, of a texture that the renderer
+    /// may allocate. This is always a power of two of at least
+    /// `MIN_TEXTURE_SIZE`. `None` means the device's own limit is used.
+    pub max_texture_size: Option<u32>,
+
+    /// The highest frame rate that movies may play at, regardless of the
+    /// frame rate they declare. `None` means there is no cap.
+    pub max_frame_rate: Option<u16>,
+
+    /// How many times per second gamepads and other input devices are polled.
+    /// `None` uses the default rate.
+    pub input_poll_hz: Option<u32>,
+
+    /// Rules restricting which URLs content may contact, in order of priority.
+    pub network_rules: Vec<NetworkRule>,
+
+    /// Whether the opened movie should be reloaded when its file changes.
+    pub auto_reload_on_change: bool,
+
+    /// The anti-aliasing mode to use for text that doesn't specify one.
+    pub default_font_antialias: FontAntialias,
+
+    /// The directory that the open file dialog was last used in.
+    pub last_open_dir: Option<PathBuf>,
+
+    /// A directory of compatibility patches to apply to movies as they are
+    /// loaded, keyed by the hash of each movie.
+    pub patches_dir: Option<PathBuf>,
+
+    /// Whether AVM errors that are normally only logged, such as operand
+    /// stack underflows, should be treated as errors.
+    pub strict_avm_errors: bool,
+
+    /// How uncaught ActionScript 3 errors should be surfaced.
+    pub uncaught_error_behavior: UncaughtErrorBehavior,
+
+    /// Whether text fields should accept input from an input method editor.
+    pub enable_ime: bool,
+
+    /// Whether AVM2 class verification should log every trait it checks.
+    pub verbose_avm2_verification: bool,
+
+    /// Whether Ruffle's own items should be shown in the right-click menu.
+    pub show_ruffle_context_menu_items: bool,
+
+    /// What to do when content asks to use a camera or microphone.
+    pub camera_microphone_permission: AllowDenyAsk,
+
+    /// What to do when content asks to enter fullscreen.
+    pub allow_fullscreen_request: AllowDenyAsk,
+
+    /// Whether movies should be paused before their first frame, so that
+    /// they can be stepped through frame by frame.
+    pub start_paused: bool,
+
+    /// Whether video should loop by default when it reaches the end.
+    pub loop_video_by_default: bool,
+
+    /// The AMF version used to encode new SharedObjects.
+    pub default_shared_object_encoding: AmfEncoding,
+
+    /// A fixed seed for the random number generator, so that content using
+    /// `Math.random` plays back the same way every time.
+    ///
+    /// This is stored as a string, as TOML integers can't hold every `u64`.
+    pub rng_seed: Option<u64>,
+
+    pub audio: AudioPreferences,
     pub log: LogPreferences,
     pub storage: StoragePreferences,
 }
@@ -203,22 +373,267 @@ impl Default for SavedGlobalPreferences {
         Self {
             graphics_backend: Default::default(),
             graphics_power_preference: Default::default(),
+            max_feature_level: None,
             language: locale,
+            keyboard_layout: None,
             output_device: None,
             mute: false,
             volume: 1.0,
+            audio_channels: Default::default(),
+            spoof_player_version: None,
+            as_api_version: None,
+            movie_zoom: HashMap::new(),
+            default_main_swf: HashMap::new(),
+            hide_cursor_after_ms: None,
+            max_execution_duration_secs: None,
+            max_content_memory_mb: None,
+            max_texture_size: None,
+            max_frame_rate: None,
+            input_poll_hz: None,
+            network_rules: Vec::new(),
+            auto_reload_on_change: false,
+            default_font_antialias: Default::default(),
+            last_open_dir: None,
+            patches_dir: None,
+            strict_avm_errors: false,
+            uncaught_error_behavior: Default::default(),
+            enable_ime: true,
+            verbose_avm2_verification: false,
+            show_ruffle_context_menu_items: true,
+            camera_microphone_permission: Default::default(),
+            allow_fullscreen_request: AllowDenyAsk::Allow,
+            start_paused: false,
+            loop_video_by_default: false,
+            default_shared_object_encoding: Default::default(),
+            rng_seed: None,
+            audio: Default::default(),
             log: Default::default(),
             storage: Default::default(),
         }
     }
 }
 
+/// The range of zoom factors that can be remembered for a movie.
+pub const MOVIE_ZOOM_RANGE: RangeInclusive<f32> = 0.25..=8.0;
+
+/// The feature levels that `max_feature_level` may be capped to.
+pub const MAX_FEATURE_LEVELS: &[&str] = &["downlevel", "gl-es-3"];
+
+/// The keyboard layouts that physical keys can be mapped from.
+pub const KEYBOARD_LAYOUTS: &[&str] = &["us", "uk", "azerty", "qwertz", "dvorak"];
+
+/// The smallest memory limit, in megabytes, that can be set for content.
+pub const MIN_CONTENT_MEMORY_MB: u32 = 16;
+
+/// The smallest texture size limit, in pixels, that can be set.
+pub const MIN_TEXTURE_SIZE: u32 = 256;
+
+/// The range of rates, in hertz, that input devices can be polled at.
+pub const INPUT_POLL_HZ_RANGE: RangeInclusive<u32> = 10..=1000;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum PreferencesError {
+    #[error("Unsupported language {0}")]
+    UnsupportedLanguage(LanguageIdentifier),
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct NetworkRule {
+    /// The URL pattern that this rule applies to.
+    pub pattern: String,
+
+    /// Whether matching URLs may be contacted (`true`) or are blocked (`false`).
+    pub allow: bool,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub enum FontAntialias {
+    Normal,
+    #[default]
+    Advanced,
+}
+
+impl FromStr for FontAntialias {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "normal" => Ok(FontAntialias::Normal),
+            "advanced" => Ok(FontAntialias::Advanced),
+            _ => Err(()),
+        }
+    }
+}
+
+impl FontAntialias {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FontAntialias::Normal => "normal",
+            FontAntialias::Advanced => "advanced",
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub enum AudioChannels {
+    /// Use as many channels as the output device has.
+    #[default]
+    Auto,
+    Mono,
+    Stereo,
+}
+
+impl FromStr for AudioChannels {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(AudioChannels::Auto),
+            "mono" => Ok(AudioChannels::Mono),
+            "stereo" => Ok(AudioChannels::Stereo),
+            _ => Err(()),
+        }
+    }
+}
+
+impl AudioChannels {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AudioChannels::Auto => "auto",
+            AudioChannels::Mono => "mono",
+            AudioChannels::Stereo => "stereo",
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub enum AllowDenyAsk {
+    Allow,
+    Deny,
+
+    /// Ask the user every time.
+    #[default]
+    Ask,
+}
+
+impl FromStr for AllowDenyAsk {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "allow" => Ok(AllowDenyAsk::Allow),
+            "deny" => Ok(AllowDenyAsk::Deny),
+            "ask" => Ok(AllowDenyAsk::Ask),
+            _ => Err(()),
+        }
+    }
+}
+
+impl AllowDenyAsk {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AllowDenyAsk::Allow => "allow",
+            AllowDenyAsk::Deny => "deny",
+            AllowDenyAsk::Ask => "ask",
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub enum UncaughtErrorBehavior {
+    /// Show the error to the user in a dialog.
+    Dialog,
+
+    /// Only write the error to the log.
+    #[default]
+    Log,
+
+    Ignore,
+}
+
+impl FromStr for UncaughtErrorBehavior {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "dialog" => Ok(UncaughtErrorBehavior::Dialog),
+            "log" => Ok(UncaughtErrorBehavior::Log),
+            "ignore" => Ok(UncaughtErrorBehavior::Ignore),
+            _ => Err(()),
+        }
+    }
+}
+
+impl UncaughtErrorBehavior {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UncaughtErrorBehavior::Dialog => "dialog",
+            UncaughtErrorBehavior::Log => "log",
+            UncaughtErrorBehavior::Ignore => "ignore",
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub enum AmfEncoding {
+    Amf0,
+    #[default]
+    Amf3,
+}
+
+impl FromStr for AmfEncoding {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "amf0" => Ok(AmfEncoding::Amf0),
+            "amf3" => Ok(AmfEncoding::Amf3),
+            _ => Err(()),
+        }
+    }
+}
+
+impl AmfEncoding {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AmfEncoding::Amf0 => "amf0",
+            AmfEncoding::Amf3 => "amf3",
+        }
+    }
+}
+
 #[derive(PartialEq, Debug, Default)]
+pub struct AudioPreferences {
+    /// The sample rate, in hertz, to open the output device with.
+    /// `None` uses the device's default rate.
+    pub sample_rate: Option<u32>,
+}
+
+#[derive(PartialEq, Debug)]
 pub struct LogPreferences {
+    /// Whether logs should be written to a file at all.
+    pub enabled: bool,
+
     pub filename_pattern: FilenamePattern,
+
+    /// The most verbose level of messages that should be logged.
+    pub level: LogLevel,
+}
+
+impl Default for LogPreferences {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            filename_pattern: Default::default(),
+            level: Default::default(),
+        }
+    }
 }
 
 #[derive(PartialEq, Debug, Default)]
 pub struct StoragePreferences {
     pub backend: storage::StorageBackend,
+
+    /// Storage backends to use for specific SWF host domains, instead of `backend`.
+    pub per_domain_overrides: HashMap<String, storage::StorageBackend>,
 }
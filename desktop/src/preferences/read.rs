@@ -1,7 +1,28 @@
-use crate::preferences::SavedGlobalPreferences;
+use crate::preferences::{
+    NetworkRule, SavedGlobalPreferences, INPUT_POLL_HZ_RANGE, KEYBOARD_LAYOUTS, MAX_FEATURE_LEVELS,
+    MIN_CONTENT_MEMORY_MB, MIN_TEXTURE_SIZE, MOVIE_ZOOM_RANGE,
+};
+use ruffle_core::PlayerBuilder;
 use ruffle_frontend_utils::parse::{DocumentHolder, ParseContext, ParseDetails, ReadExt};
 use toml_edit::DocumentMut;
 
+/// Environment variables that override preferences, and the key of the
+/// preference that each one overrides.
+///
+/// Overrides take precedence over the preferences file, but are never written
+/// back to it. They are parsed in the same way as the corresponding string
+/// value in the file would be.
+pub const ENV_OVERRIDES: &[(&str, &str)] = &[
+    ("RUFFLE_GRAPHICS_BACKEND", "graphics_backend"),
+    (
+        "RUFFLE_GRAPHICS_POWER_PREFERENCE",
+        "graphics_power_preference",
+    ),
+    ("RUFFLE_LANGUAGE", "language"),
+    ("RUFFLE_OUTPUT_DEVICE", "output_device"),
+    ("RUFFLE_SPOOF_PLAYER_VERSION", "spoof_player_version"),
+];
+
 /// Read the given preferences into a **guaranteed valid** `SavedGlobalPreferences`,
 /// recording any possible warnings encountered along the way.
 ///
@@ -11,69 +32,408 @@ use toml_edit::DocumentMut;
 /// Default values are used wherever an unknown or invalid value is found;
 /// this is to support the case of, for example, a later version having different supported
 /// backends than an older version.
-pub fn read_preferences(input: &str) -> ParseDetails<SavedGlobalPreferences> {
+///
+/// Any `ENV_OVERRIDES` found by `env` are applied on top of the given preferences.
+pub fn read_preferences_with_env(
+    input: &str,
+    env: impl Fn(&str) -> Option<String>,
+) -> ParseDetails<SavedGlobalPreferences> {
+    let mut cx = ParseContext::default();
     let document = match input.parse::<DocumentMut>() {
         Ok(document) => document,
         Err(e) => {
-            return ParseDetails {
-                result: Default::default(),
-                warnings: vec![format!("Invalid TOML: {e}")],
-            }
+            cx.add_warning(format!("Invalid TOML: {e}"));
+            DocumentMut::new()
         }
     };
 
     let mut result = SavedGlobalPreferences::default();
-    let mut cx = ParseContext::default();
+    read_values(&document, &mut result, &mut cx);
+
+    let mut overrides = DocumentMut::new();
+    for (variable, key) in ENV_OVERRIDES {
+        if let Some(value) = env(variable) {
+            overrides[key] = toml_edit::value(value);
+        }
+    }
+    read_values(&overrides, &mut result, &mut cx);
+
+    ParseDetails {
+        result: DocumentHolder::new(result, document),
+        warnings: cx.warnings,
+    }
+}
 
-    if let Some(value) = document.parse_from_str(&mut cx, "graphics_backend") {
+/// Like `read_preferences_with_env`, without any environment overrides.
+#[cfg(test)]
+pub fn read_preferences(input: &str) -> ParseDetails<SavedGlobalPreferences> {
+    read_preferences_with_env(input, |_| None)
+}
+
+fn read_values(document: &DocumentMut, result: &mut SavedGlobalPreferences, cx: &mut ParseContext) {
+    if let Some(value) = document.parse_from_str(cx, "graphics_backend") {
         result.graphics_backend = value;
     };
 
-    if let Some(value) = document.parse_from_str(&mut cx, "graphics_power_preference") {
+    if let Some(value) = document.parse_from_str(cx, "graphics_power_preference") {
         result.graphics_power_preference = value;
     };
 
-    if let Some(value) = document.parse_from_str(&mut cx, "language") {
+    if let Some(value) = document.parse_from_str::<String>(cx, "max_feature_level") {
+        if MAX_FEATURE_LEVELS.contains(&value.as_str()) {
+            result.max_feature_level = Some(value);
+        } else {
+            cx.add_warning(format!(
+                "Invalid max_feature_level: unsupported value {value:?}"
+            ));
+        }
+    };
+
+    if let Some(value) = document.parse_from_str(cx, "language") {
         result.language = value;
     };
 
-    if let Some(value) = document.parse_from_str(&mut cx, "output_device") {
+    if let Some(value) = document.parse_from_str::<String>(cx, "keyboard_layout") {
+        if KEYBOARD_LAYOUTS.contains(&value.as_str()) {
+            result.keyboard_layout = Some(value);
+        } else {
+            cx.add_warning(format!(
+                "Invalid keyboard_layout: unsupported value {value:?}"
+            ));
+        }
+    };
+
+    if let Some(value) = document.parse_from_str(cx, "output_device") {
         result.output_device = Some(value);
     };
 
-    if let Some(value) = document.get_float(&mut cx, "volume") {
+    if let Some(value) = document.parse_from_str(cx, "last_open_dir") {
+        result.last_open_dir = Some(value);
+    };
+
+    if let Some(value) = document.parse_from_str(cx, "patches_dir") {
+        result.patches_dir = Some(value);
+    };
+
+    if let Some(value) = document.get_float(cx, "volume") {
         result.volume = value.clamp(0.0, 1.0) as f32;
     };
 
-    if let Some(value) = document.get_bool(&mut cx, "mute") {
+    if let Some(value) = document.parse_from_str(cx, "audio_channels") {
+        result.audio_channels = value;
+    };
+
+    if let Some(value) = document.get_bool(cx, "mute") {
         result.mute = value;
     };
 
-    document.get_table_like(&mut cx, "log", |cx, log| {
+    if let Some(value) = document.get_bool(cx, "start_paused") {
+        result.start_paused = value;
+    };
+
+    if let Some(value) = document.get_bool(cx, "loop_video_by_default") {
+        result.loop_video_by_default = value;
+    };
+
+    if let Some(value) = document.parse_from_str(cx, "default_shared_object_encoding") {
+        result.default_shared_object_encoding = value;
+    };
+
+    if let Some(value) = document.parse_from_str(cx, "rng_seed") {
+        result.rng_seed = Some(value);
+    };
+
+    if let Some(value) = document.parse_from_str(cx, "default_font_antialias") {
+        result.default_font_antialias = value;
+    };
+
+    if let Some(value) = document.get_bool(cx, "enable_ime") {
+        result.enable_ime = value;
+    };
+
+    if let Some(value) = document.parse_from_str(cx, "camera_microphone_permission") {
+        result.camera_microphone_permission = value;
+    };
+
+    if let Some(value) = document.parse_from_str(cx, "allow_fullscreen_request") {
+        result.allow_fullscreen_request = value;
+    };
+
+    if let Some(value) = document.get_bool(cx, "show_ruffle_context_menu_items") {
+        result.show_ruffle_context_menu_items = value;
+    };
+
+    if let Some(value) = document.get_bool(cx, "verbose_avm2_verification") {
+        result.verbose_avm2_verification = value;
+    };
+
+    if let Some(value) = document.get_bool(cx, "strict_avm_errors") {
+        result.strict_avm_errors = value;
+    };
+
+    if let Some(value) = document.parse_from_str(cx, "uncaught_error_behavior") {
+        result.uncaught_error_behavior = value;
+    };
+
+    if let Some(value) = document.get_bool(cx, "auto_reload_on_change") {
+        result.auto_reload_on_change = value;
+    };
+
+    if let Some(value) = document.parse_from_str::<String>(cx, "spoof_player_version") {
+        if let Some(version) = parse_player_version(&value) {
+            result.spoof_player_version = Some(version);
+        } else {
+            cx.add_warning(format!(
+                "Invalid spoof_player_version: unsupported value {value:?}"
+            ));
+        }
+    };
+
+    if let Some(value) = document.get_integer(cx, "as_api_version") {
+        match u16::try_from(value) {
+            Ok(version) if PlayerBuilder::is_valid_as_api_version(version) => {
+                result.as_api_version = Some(version);
+            }
+            Ok(_) => {
+                cx.add_warning(format!("Invalid as_api_version: unsupported value {value}"));
+            }
+            Err(_) => {
+                cx.add_warning(format!("Invalid as_api_version: {value} is out of range"));
+            }
+        }
+    };
+
+    if let Some(value) = document.get_integer(cx, "hide_cursor_after_ms") {
+        if let Ok(value) = u32::try_from(value) {
+            result.hide_cursor_after_ms = Some(value);
+        } else {
+            cx.add_warning(format!(
+                "Invalid hide_cursor_after_ms: {value} is out of range"
+            ));
+        }
+    };
+
+    if let Some(value) = document.get_float(cx, "max_execution_duration_secs") {
+        if value > 0.0 {
+            result.max_execution_duration_secs = Some(value);
+        } else {
+            cx.add_warning(format!(
+                "Invalid max_execution_duration_secs: {value} is not a positive duration"
+            ));
+        }
+    };
+
+    if let Some(value) = document.get_integer(cx, "max_content_memory_mb") {
+        match u32::try_from(value) {
+            Ok(value) if value >= MIN_CONTENT_MEMORY_MB => {
+                result.max_content_memory_mb = Some(value);
+            }
+            _ => cx.add_warning(format!(
+                "Invalid max_content_memory_mb: {value} is out of range (minimum {MIN_CONTENT_MEMORY_MB})"
+            )),
+        }
+    };
+
+    if let Some(value) = document.get_integer(cx, "max_texture_size") {
+        match u32::try_from(value) {
+            Ok(value) if value >= MIN_TEXTURE_SIZE && value.is_power_of_two() => {
+                result.max_texture_size = Some(value);
+            }
+            _ => cx.add_warning(format!(
+                "Invalid max_texture_size: {value} is not a power of two of at least {MIN_TEXTURE_SIZE}"
+            )),
+        }
+    };
+
+    if let Some(value) = document.get_integer(cx, "max_frame_rate") {
+        if let Ok(value) = u16::try_from(value) {
+            result.max_frame_rate = Some(value);
+        } else {
+            cx.add_warning(format!("Invalid max_frame_rate: {value} is out of range"));
+        }
+    };
+
+    if let Some(value) = document.get_integer(cx, "input_poll_hz") {
+        match u32::try_from(value) {
+            Ok(value) if INPUT_POLL_HZ_RANGE.contains(&value) => {
+                result.input_poll_hz = Some(value);
+            }
+            _ => cx.add_warning(format!(
+                "Invalid input_poll_hz: {value} is out of range ({} to {})",
+                INPUT_POLL_HZ_RANGE.start(),
+                INPUT_POLL_HZ_RANGE.end()
+            )),
+        }
+    };
+
+    document.get_array_of_tables(cx, "network_rule", |cx, network_rules| {
+        for rule in network_rules.iter() {
+            let Some(pattern) = rule.parse_from_str::<String>(cx, "pattern") else {
+                cx.add_warning(format!("Invalid {}: missing pattern", cx.path()));
+                continue;
+            };
+            // Rules block by default, so that a typo never widens access.
+            let allow = rule.get_bool(cx, "allow").unwrap_or(false);
+
+            result.network_rules.push(NetworkRule { pattern, allow });
+        }
+    });
+
+    document.get_table_like(cx, "movie_zoom", |cx, movie_zoom| {
+        for (path, item) in movie_zoom.iter() {
+            if let Some(factor) = item.as_float() {
+                let factor = factor as f32;
+                if MOVIE_ZOOM_RANGE.contains(&factor) {
+                    result.movie_zoom.insert(path.to_string(), factor);
+                } else {
+                    cx.add_warning(format!(
+                        "Invalid {}.{path}: zoom factor {factor} is out of range",
+                        cx.path()
+                    ));
+                }
+            } else {
+                cx.add_warning(format!(
+                    "Invalid {}.{path}: expected float but found {}",
+                    cx.path(),
+                    item.type_name()
+                ));
+            }
+        }
+    });
+
+    document.get_table_like(cx, "default_main_swf", |cx, default_main_swf| {
+        for (archive, item) in default_main_swf.iter() {
+            if let Some(entry) = item.as_str() {
+                result
+                    .default_main_swf
+                    .insert(archive.to_string(), entry.to_string());
+            } else {
+                cx.add_warning(format!(
+                    "Invalid {}.{archive}: expected string but found {}",
+                    cx.path(),
+                    item.type_name()
+                ));
+            }
+        }
+    });
+
+    document.get_table_like(cx, "audio", |cx, audio| {
+        if let Some(value) = audio.get_integer(cx, "sample_rate") {
+            match u32::try_from(value) {
+                Ok(value) if value > 0 => result.audio.sample_rate = Some(value),
+                _ => cx.add_warning(format!(
+                    "Invalid audio.sample_rate: {value} is out of range"
+                )),
+            }
+        };
+    });
+
+    document.get_table_like(cx, "log", |cx, log| {
+        if let Some(value) = log.get_bool(cx, "enabled") {
+            result.log.enabled = value;
+        };
+
         if let Some(value) = log.parse_from_str(cx, "filename_pattern") {
             result.log.filename_pattern = value;
         };
+
+        if let Some(value) = log.parse_from_str(cx, "level") {
+            result.log.level = value;
+        };
     });
 
-    document.get_table_like(&mut cx, "storage", |cx, storage| {
+    document.get_table_like(cx, "storage", |cx, storage| {
         if let Some(value) = storage.parse_from_str(cx, "backend") {
             result.storage.backend = value;
         }
+
+        storage.get_table_like(cx, "per_domain_overrides", |cx, overrides| {
+            for (domain, item) in overrides.iter() {
+                if let Some(str) = item.as_str() {
+                    if let Ok(value) = str.parse() {
+                        result
+                            .storage
+                            .per_domain_overrides
+                            .insert(domain.to_string(), value);
+                    } else {
+                        cx.add_warning(format!(
+                            "Invalid {}.{domain}: unsupported value {str:?}",
+                            cx.path()
+                        ));
+                    }
+                } else {
+                    cx.add_warning(format!(
+                        "Invalid {}.{domain}: expected string but found {}",
+                        cx.path(),
+                        item.type_name()
+                    ));
+                }
+            }
+        });
     });
+}
 
-    ParseDetails {
-        result: DocumentHolder::new(result, document),
-        warnings: cx.warnings,
+/// Parse a dotted player version such as `32.0.0.465`.
+fn parse_player_version(version: &str) -> Option<(u8, u8, u16, u16)> {
+    let mut components = version.split('.');
+    let version = (
+        components.next()?.parse().ok()?,
+        components.next()?.parse().ok()?,
+        components.next()?.parse().ok()?,
+        components.next()?.parse().ok()?,
+    );
+
+    if components.next().is_some() {
+        return None;
     }
+
+    Some(version)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::log::FilenamePattern;
-    use crate::preferences::{storage::StorageBackend, LogPreferences, StoragePreferences};
+    use crate::log::{FilenamePattern, LogLevel};
+    use crate::preferences::{
+        storage::StorageBackend, AllowDenyAsk, AmfEncoding, AudioChannels, AudioPreferences,
+        FontAntialias, LogPreferences, StoragePreferences, UncaughtErrorBehavior,
+    };
     use fluent_templates::loader::langid;
     use ruffle_render_wgpu::clap::{GraphicsBackend, PowerPreference};
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    #[test]
+    fn env_overrides() {
+        let env = |name: &str| match name {
+            "RUFFLE_GRAPHICS_BACKEND" => Some("vulkan".to_string()),
+            "RUFFLE_LANGUAGE" => Some("???".to_string()),
+            _ => None,
+        };
+
+        let result = read_preferences_with_env(
+            "graphics_backend = \"dx12\"\ngraphics_power_preference = \"low\"\n",
+            env,
+        );
+        assert_eq!(
+            &SavedGlobalPreferences {
+                graphics_backend: GraphicsBackend::Vulkan,
+                graphics_power_preference: PowerPreference::Low,
+                ..Default::default()
+            },
+            result.values()
+        );
+        assert_eq!(
+            vec!["Invalid language: unsupported value \"???\"".to_string()],
+            result.warnings
+        );
+
+        // Overrides are not written back to the document.
+        assert_eq!(
+            "graphics_backend = \"dx12\"\ngraphics_power_preference = \"low\"\n",
+            result.result.serialize()
+        );
+    }
 
     #[test]
     fn invalid_toml() {
@@ -205,87 +565,131 @@ mod tests {
     }
 
     #[test]
-    fn invalid_output_device() {
-        let result = read_preferences("output_device = 5");
-
+    fn max_feature_level() {
+        let result = read_preferences("max_feature_level = \"gl-es-3\"");
         assert_eq!(
             &SavedGlobalPreferences {
-                output_device: None,
+                max_feature_level: Some("gl-es-3".to_string()),
                 ..Default::default()
             },
             result.values()
         );
+        assert_eq!(Vec::<String>::new(), result.warnings);
+
+        let result = read_preferences("max_feature_level = \"gl-es-1\"");
+        assert_eq!(&SavedGlobalPreferences::default(), result.values());
         assert_eq!(
-            vec!["Invalid output_device: expected string but found integer".to_string()],
+            vec!["Invalid max_feature_level: unsupported value \"gl-es-1\"".to_string()],
             result.warnings
         );
     }
 
     #[test]
-    fn mute() {
-        let result = read_preferences("mute = \"false\"");
+    fn keyboard_layout() {
+        let result = read_preferences("keyboard_layout = \"azerty\"");
         assert_eq!(
             &SavedGlobalPreferences {
-                mute: false,
+                keyboard_layout: Some("azerty".to_string()),
                 ..Default::default()
             },
             result.values()
         );
+        assert_eq!(Vec::<String>::new(), result.warnings);
+
+        let result = read_preferences("keyboard_layout = \"colemak\"");
+        assert_eq!(&SavedGlobalPreferences::default(), result.values());
         assert_eq!(
-            vec!["Invalid mute: expected boolean but found string".to_string()],
+            vec!["Invalid keyboard_layout: unsupported value \"colemak\"".to_string()],
             result.warnings
         );
+    }
 
-        let result = read_preferences("mute = true");
+    #[test]
+    fn last_open_dir() {
+        let result = read_preferences("last_open_dir = \"/home/user/swfs\"");
         assert_eq!(
             &SavedGlobalPreferences {
-                mute: true,
+                last_open_dir: Some(PathBuf::from("/home/user/swfs")),
                 ..Default::default()
             },
             result.values()
         );
         assert_eq!(Vec::<String>::new(), result.warnings);
 
-        let result = read_preferences("");
+        let result = read_preferences("last_open_dir = 5");
+        assert_eq!(&SavedGlobalPreferences::default(), result.values());
+        assert_eq!(
+            vec!["Invalid last_open_dir: expected string but found integer".to_string()],
+            result.warnings
+        );
+    }
+
+    #[test]
+    fn patches_dir() {
+        let result = read_preferences("patches_dir = 'C:\\Ruffle\\patches with spaces'");
         assert_eq!(
             &SavedGlobalPreferences {
-                mute: false,
+                patches_dir: Some(PathBuf::from("C:\\Ruffle\\patches with spaces")),
                 ..Default::default()
             },
             result.values()
         );
         assert_eq!(Vec::<String>::new(), result.warnings);
+
+        let result = read_preferences("patches_dir = true");
+        assert_eq!(&SavedGlobalPreferences::default(), result.values());
+        assert_eq!(
+            vec!["Invalid patches_dir: expected string but found boolean".to_string()],
+            result.warnings
+        );
     }
 
     #[test]
-    fn volume() {
-        let result = read_preferences("volume = \"0.5\"");
+    fn invalid_output_device() {
+        let result = read_preferences("output_device = 5");
+
         assert_eq!(
             &SavedGlobalPreferences {
-                volume: 1.0,
+                output_device: None,
                 ..Default::default()
             },
             result.values()
         );
         assert_eq!(
-            vec!["Invalid volume: expected float but found string".to_string()],
+            vec!["Invalid output_device: expected string but found integer".to_string()],
             result.warnings
         );
+    }
 
-        let result = read_preferences("volume = 0.5");
+    #[test]
+    fn mute() {
+        let result = read_preferences("mute = \"false\"");
         assert_eq!(
             &SavedGlobalPreferences {
-                volume: 0.5,
+                mute: false,
+                ..Default::default()
+            },
+            result.values()
+        );
+        assert_eq!(
+            vec!["Invalid mute: expected boolean but found string".to_string()],
+            result.warnings
+        );
+
+        let result = read_preferences("mute = true");
+        assert_eq!(
+            &SavedGlobalPreferences {
+                mute: true,
                 ..Default::default()
             },
             result.values()
         );
         assert_eq!(Vec::<String>::new(), result.warnings);
 
-        let result = read_preferences("volume = -1.0");
+        let result = read_preferences("");
         assert_eq!(
             &SavedGlobalPreferences {
-                volume: 0.0,
+                mute: false,
                 ..Default::default()
             },
             result.values()
@@ -294,79 +698,980 @@ mod tests {
     }
 
     #[test]
-    fn log_filename() {
-        let result = read_preferences("log = {filename_pattern = 5}");
+    fn loop_video_by_default() {
+        let result = read_preferences("loop_video_by_default = true");
         assert_eq!(
             &SavedGlobalPreferences {
-                log: LogPreferences {
-                    ..Default::default()
-                },
+                loop_video_by_default: true,
                 ..Default::default()
             },
             result.values()
         );
-        assert_eq!(
-            vec!["Invalid log.filename_pattern: expected string but found integer".to_string()],
-            result.warnings
-        );
+        assert_eq!(Vec::<String>::new(), result.warnings);
 
-        let result = read_preferences("log = {filename_pattern = \"???\"}");
+        let result = read_preferences("loop_video_by_default = \"yes\"");
         assert_eq!(&SavedGlobalPreferences::default(), result.values());
         assert_eq!(
-            vec!["Invalid log.filename_pattern: unsupported value \"???\"".to_string()],
+            vec!["Invalid loop_video_by_default: expected boolean but found string".to_string()],
             result.warnings
         );
 
-        let result = read_preferences("log = {filename_pattern = \"with_timestamp\"}");
+        let result = read_preferences("");
+        assert!(!result.values().loop_video_by_default);
+        assert_eq!(Vec::<String>::new(), result.warnings);
+    }
+
+    #[test]
+    fn default_shared_object_encoding() {
+        for (value, encoding) in [("amf0", AmfEncoding::Amf0), ("amf3", AmfEncoding::Amf3)] {
+            let result = read_preferences(&format!("default_shared_object_encoding = \"{value}\""));
+            assert_eq!(
+                &SavedGlobalPreferences {
+                    default_shared_object_encoding: encoding,
+                    ..Default::default()
+                },
+                result.values()
+            );
+            assert_eq!(Vec::<String>::new(), result.warnings);
+        }
+
+        let result = read_preferences("default_shared_object_encoding = \"json\"");
         assert_eq!(
             &SavedGlobalPreferences {
-                log: LogPreferences {
-                    filename_pattern: FilenamePattern::WithTimestamp,
-                },
+                default_shared_object_encoding: AmfEncoding::Amf3,
                 ..Default::default()
             },
             result.values()
         );
-        assert_eq!(Vec::<String>::new(), result.warnings);
-    }
-
-    #[test]
-    fn log() {
-        let result = read_preferences("log = \"yes\"");
-        assert_eq!(&SavedGlobalPreferences::default(), result.values());
         assert_eq!(
-            vec!["Invalid log: expected table but found string".to_string()],
+            vec!["Invalid default_shared_object_encoding: unsupported value \"json\"".to_string()],
             result.warnings
         );
     }
 
     #[test]
-    fn storage_backend() {
-        let result = read_preferences("storage = {backend = 5}");
+    fn rng_seed() {
+        for seed in [0, 12345, u64::MAX] {
+            let result = read_preferences(&format!("rng_seed = \"{seed}\""));
+            assert_eq!(
+                &SavedGlobalPreferences {
+                    rng_seed: Some(seed),
+                    ..Default::default()
+                },
+                result.values()
+            );
+            assert_eq!(Vec::<String>::new(), result.warnings);
+        }
+
+        let result = read_preferences("rng_seed = \"-1\"");
         assert_eq!(&SavedGlobalPreferences::default(), result.values());
         assert_eq!(
-            vec!["Invalid storage.backend: expected string but found integer".to_string()],
+            vec!["Invalid rng_seed: unsupported value \"-1\"".to_string()],
             result.warnings
         );
 
-        let result = read_preferences("storage = {backend = \"???\"}");
+        let result = read_preferences("rng_seed = 5");
         assert_eq!(&SavedGlobalPreferences::default(), result.values());
         assert_eq!(
-            vec!["Invalid storage.backend: unsupported value \"???\"".to_string()],
+            vec!["Invalid rng_seed: expected string but found integer".to_string()],
             result.warnings
         );
+    }
 
-        let result = read_preferences("storage = {backend = \"memory\"}");
+    #[test]
+    fn start_paused() {
+        let result = read_preferences("start_paused = true");
         assert_eq!(
             &SavedGlobalPreferences {
-                storage: StoragePreferences {
-                    backend: StorageBackend::Memory,
-                },
+                start_paused: true,
                 ..Default::default()
             },
             result.values()
         );
         assert_eq!(Vec::<String>::new(), result.warnings);
+
+        let result = read_preferences("start_paused = 1");
+        assert_eq!(&SavedGlobalPreferences::default(), result.values());
+        assert_eq!(
+            vec!["Invalid start_paused: expected boolean but found integer".to_string()],
+            result.warnings
+        );
+
+        let result = read_preferences("");
+        assert!(!result.values().start_paused);
+        assert_eq!(Vec::<String>::new(), result.warnings);
+    }
+
+    #[test]
+    fn default_font_antialias() {
+        let result = read_preferences("default_font_antialias = \"normal\"");
+        assert_eq!(
+            &SavedGlobalPreferences {
+                default_font_antialias: FontAntialias::Normal,
+                ..Default::default()
+            },
+            result.values()
+        );
+        assert_eq!(Vec::<String>::new(), result.warnings);
+
+        let result = read_preferences("default_font_antialias = \"advanced\"");
+        assert_eq!(
+            &SavedGlobalPreferences {
+                default_font_antialias: FontAntialias::Advanced,
+                ..Default::default()
+            },
+            result.values()
+        );
+        assert_eq!(Vec::<String>::new(), result.warnings);
+
+        let result = read_preferences("default_font_antialias = \"subpixel\"");
+        assert_eq!(
+            &SavedGlobalPreferences {
+                default_font_antialias: FontAntialias::Advanced,
+                ..Default::default()
+            },
+            result.values()
+        );
+        assert_eq!(
+            vec!["Invalid default_font_antialias: unsupported value \"subpixel\"".to_string()],
+            result.warnings
+        );
+    }
+
+    #[test]
+    fn enable_ime() {
+        let result = read_preferences("enable_ime = false");
+        assert_eq!(
+            &SavedGlobalPreferences {
+                enable_ime: false,
+                ..Default::default()
+            },
+            result.values()
+        );
+        assert_eq!(Vec::<String>::new(), result.warnings);
+
+        let result = read_preferences("enable_ime = 0");
+        assert_eq!(&SavedGlobalPreferences::default(), result.values());
+        assert_eq!(
+            vec!["Invalid enable_ime: expected boolean but found integer".to_string()],
+            result.warnings
+        );
+
+        let result = read_preferences("");
+        assert!(result.values().enable_ime);
+        assert_eq!(Vec::<String>::new(), result.warnings);
+    }
+
+    #[test]
+    fn camera_microphone_permission() {
+        for (value, permission) in [
+            ("allow", AllowDenyAsk::Allow),
+            ("deny", AllowDenyAsk::Deny),
+            ("ask", AllowDenyAsk::Ask),
+        ] {
+            let result = read_preferences(&format!("camera_microphone_permission = \"{value}\""));
+            assert_eq!(
+                &SavedGlobalPreferences {
+                    camera_microphone_permission: permission,
+                    ..Default::default()
+                },
+                result.values()
+            );
+            assert_eq!(Vec::<String>::new(), result.warnings);
+        }
+
+        let result = read_preferences("camera_microphone_permission = \"sometimes\"");
+        assert_eq!(
+            &SavedGlobalPreferences {
+                camera_microphone_permission: AllowDenyAsk::Ask,
+                ..Default::default()
+            },
+            result.values()
+        );
+        assert_eq!(
+            vec![
+                "Invalid camera_microphone_permission: unsupported value \"sometimes\"".to_string()
+            ],
+            result.warnings
+        );
+    }
+
+    #[test]
+    fn allow_fullscreen_request() {
+        for (value, permission) in [
+            ("allow", AllowDenyAsk::Allow),
+            ("deny", AllowDenyAsk::Deny),
+            ("ask", AllowDenyAsk::Ask),
+        ] {
+            let result = read_preferences(&format!("allow_fullscreen_request = \"{value}\""));
+            assert_eq!(
+                &SavedGlobalPreferences {
+                    allow_fullscreen_request: permission,
+                    ..Default::default()
+                },
+                result.values()
+            );
+            assert_eq!(Vec::<String>::new(), result.warnings);
+        }
+
+        let result = read_preferences("allow_fullscreen_request = \"sometimes\"");
+        assert_eq!(
+            &SavedGlobalPreferences {
+                allow_fullscreen_request: AllowDenyAsk::Allow,
+                ..Default::default()
+            },
+            result.values()
+        );
+        assert_eq!(
+            vec!["Invalid allow_fullscreen_request: unsupported value \"sometimes\"".to_string()],
+            result.warnings
+        );
+    }
+
+    #[test]
+    fn show_ruffle_context_menu_items() {
+        let result = read_preferences("show_ruffle_context_menu_items = false");
+        assert_eq!(
+            &SavedGlobalPreferences {
+                show_ruffle_context_menu_items: false,
+                ..Default::default()
+            },
+            result.values()
+        );
+        assert_eq!(Vec::<String>::new(), result.warnings);
+
+        let result = read_preferences("show_ruffle_context_menu_items = \"no\"");
+        assert_eq!(&SavedGlobalPreferences::default(), result.values());
+        assert_eq!(
+            vec![
+                "Invalid show_ruffle_context_menu_items: expected boolean but found string"
+                    .to_string()
+            ],
+            result.warnings
+        );
+
+        let result = read_preferences("");
+        assert!(result.values().show_ruffle_context_menu_items);
+        assert_eq!(Vec::<String>::new(), result.warnings);
+    }
+
+    #[test]
+    fn verbose_avm2_verification() {
+        let result = read_preferences("verbose_avm2_verification = true");
+        assert_eq!(
+            &SavedGlobalPreferences {
+                verbose_avm2_verification: true,
+                ..Default::default()
+            },
+            result.values()
+        );
+        assert_eq!(Vec::<String>::new(), result.warnings);
+
+        let result = read_preferences("verbose_avm2_verification = \"yes\"");
+        assert_eq!(&SavedGlobalPreferences::default(), result.values());
+        assert_eq!(
+            vec![
+                "Invalid verbose_avm2_verification: expected boolean but found string".to_string()
+            ],
+            result.warnings
+        );
+    }
+
+    #[test]
+    fn strict_avm_errors() {
+        let result = read_preferences("strict_avm_errors = true");
+        assert_eq!(
+            &SavedGlobalPreferences {
+                strict_avm_errors: true,
+                ..Default::default()
+            },
+            result.values()
+        );
+        assert_eq!(Vec::<String>::new(), result.warnings);
+
+        let result = read_preferences("strict_avm_errors = \"yes\"");
+        assert_eq!(&SavedGlobalPreferences::default(), result.values());
+        assert_eq!(
+            vec!["Invalid strict_avm_errors: expected boolean but found string".to_string()],
+            result.warnings
+        );
+
+        let result = read_preferences("");
+        assert!(!result.values().strict_avm_errors);
+        assert_eq!(Vec::<String>::new(), result.warnings);
+    }
+
+    #[test]
+    fn uncaught_error_behavior() {
+        for (value, behavior) in [
+            ("dialog", UncaughtErrorBehavior::Dialog),
+            ("log", UncaughtErrorBehavior::Log),
+            ("ignore", UncaughtErrorBehavior::Ignore),
+        ] {
+            let result = read_preferences(&format!("uncaught_error_behavior = \"{value}\""));
+            assert_eq!(
+                &SavedGlobalPreferences {
+                    uncaught_error_behavior: behavior,
+                    ..Default::default()
+                },
+                result.values()
+            );
+            assert_eq!(Vec::<String>::new(), result.warnings);
+        }
+
+        let result = read_preferences("uncaught_error_behavior = \"crash\"");
+        assert_eq!(
+            &SavedGlobalPreferences {
+                uncaught_error_behavior: UncaughtErrorBehavior::Log,
+                ..Default::default()
+            },
+            result.values()
+        );
+        assert_eq!(
+            vec!["Invalid uncaught_error_behavior: unsupported value \"crash\"".to_string()],
+            result.warnings
+        );
+    }
+
+    #[test]
+    fn auto_reload_on_change() {
+        let result = read_preferences("auto_reload_on_change = 1");
+        assert_eq!(&SavedGlobalPreferences::default(), result.values());
+        assert_eq!(
+            vec!["Invalid auto_reload_on_change: expected boolean but found integer".to_string()],
+            result.warnings
+        );
+
+        let result = read_preferences("auto_reload_on_change = true");
+        assert_eq!(
+            &SavedGlobalPreferences {
+                auto_reload_on_change: true,
+                ..Default::default()
+            },
+            result.values()
+        );
+        assert_eq!(Vec::<String>::new(), result.warnings);
+
+        let result = read_preferences("");
+        assert!(!result.values().auto_reload_on_change);
+        assert_eq!(Vec::<String>::new(), result.warnings);
+    }
+
+    #[test]
+    fn audio_channels() {
+        for (value, channels) in [
+            ("auto", AudioChannels::Auto),
+            ("mono", AudioChannels::Mono),
+            ("stereo", AudioChannels::Stereo),
+        ] {
+            let result = read_preferences(&format!("audio_channels = \"{value}\""));
+            assert_eq!(
+                &SavedGlobalPreferences {
+                    audio_channels: channels,
+                    ..Default::default()
+                },
+                result.values()
+            );
+            assert_eq!(Vec::<String>::new(), result.warnings);
+        }
+
+        let result = read_preferences("audio_channels = \"surround\"");
+        assert_eq!(
+            &SavedGlobalPreferences {
+                audio_channels: AudioChannels::Auto,
+                ..Default::default()
+            },
+            result.values()
+        );
+        assert_eq!(
+            vec!["Invalid audio_channels: unsupported value \"surround\"".to_string()],
+            result.warnings
+        );
+    }
+
+    #[test]
+    fn volume() {
+        let result = read_preferences("volume = \"0.5\"");
+        assert_eq!(
+            &SavedGlobalPreferences {
+                volume: 1.0,
+                ..Default::default()
+            },
+            result.values()
+        );
+        assert_eq!(
+            vec!["Invalid volume: expected float but found string".to_string()],
+            result.warnings
+        );
+
+        let result = read_preferences("volume = 0.5");
+        assert_eq!(
+            &SavedGlobalPreferences {
+                volume: 0.5,
+                ..Default::default()
+            },
+            result.values()
+        );
+        assert_eq!(Vec::<String>::new(), result.warnings);
+
+        let result = read_preferences("volume = -1.0");
+        assert_eq!(
+            &SavedGlobalPreferences {
+                volume: 0.0,
+                ..Default::default()
+            },
+            result.values()
+        );
+        assert_eq!(Vec::<String>::new(), result.warnings);
+    }
+
+    #[test]
+    fn audio_sample_rate() {
+        let result = read_preferences("[audio]\nsample_rate = 48000");
+        assert_eq!(
+            &SavedGlobalPreferences {
+                audio: AudioPreferences {
+                    sample_rate: Some(48000),
+                },
+                ..Default::default()
+            },
+            result.values()
+        );
+        assert_eq!(Vec::<String>::new(), result.warnings);
+
+        let result = read_preferences("audio = {sample_rate = 0}");
+        assert_eq!(&SavedGlobalPreferences::default(), result.values());
+        assert_eq!(
+            vec!["Invalid audio.sample_rate: 0 is out of range".to_string()],
+            result.warnings
+        );
+
+        let result = read_preferences("audio = {sample_rate = \"high\"}");
+        assert_eq!(&SavedGlobalPreferences::default(), result.values());
+        assert_eq!(
+            vec!["Invalid audio.sample_rate: expected integer but found string".to_string()],
+            result.warnings
+        );
+    }
+
+    #[test]
+    fn log_filename() {
+        let result = read_preferences("log = {filename_pattern = 5}");
+        assert_eq!(
+            &SavedGlobalPreferences {
+                log: LogPreferences {
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            result.values()
+        );
+        assert_eq!(
+            vec!["Invalid log.filename_pattern: expected string but found integer".to_string()],
+            result.warnings
+        );
+
+        let result = read_preferences("log = {filename_pattern = \"???\"}");
+        assert_eq!(&SavedGlobalPreferences::default(), result.values());
+        assert_eq!(
+            vec!["Invalid log.filename_pattern: unsupported value \"???\"".to_string()],
+            result.warnings
+        );
+
+        let result = read_preferences("log = {filename_pattern = \"with_timestamp\"}");
+        assert_eq!(
+            &SavedGlobalPreferences {
+                log: LogPreferences {
+                    filename_pattern: FilenamePattern::WithTimestamp,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            result.values()
+        );
+        assert_eq!(Vec::<String>::new(), result.warnings);
+    }
+
+    #[test]
+    fn log_level() {
+        let result =
+            read_preferences("[log]\nfilename_pattern = \"with_timestamp\"\nlevel = \"trace\"");
+        assert_eq!(
+            &SavedGlobalPreferences {
+                log: LogPreferences {
+                    filename_pattern: FilenamePattern::WithTimestamp,
+                    level: LogLevel::Trace,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            result.values()
+        );
+        assert_eq!(Vec::<String>::new(), result.warnings);
+
+        let result = read_preferences("log = {level = \"verbose\"}");
+        assert_eq!(&SavedGlobalPreferences::default(), result.values());
+        assert_eq!(LogLevel::Info, result.values().log.level);
+        assert_eq!(
+            vec!["Invalid log.level: unsupported value \"verbose\"".to_string()],
+            result.warnings
+        );
+    }
+
+    #[test]
+    fn log_enabled() {
+        let result = read_preferences("log = {enabled = \"no\"}");
+        assert_eq!(&SavedGlobalPreferences::default(), result.values());
+        assert_eq!(
+            vec!["Invalid log.enabled: expected boolean but found string".to_string()],
+            result.warnings
+        );
+
+        let result =
+            read_preferences("[log]\nenabled = false\nfilename_pattern = \"with_timestamp\"");
+        assert_eq!(
+            &SavedGlobalPreferences {
+                log: LogPreferences {
+                    enabled: false,
+                    filename_pattern: FilenamePattern::WithTimestamp,
+                    level: LogLevel::Info,
+                },
+                ..Default::default()
+            },
+            result.values()
+        );
+        assert_eq!(Vec::<String>::new(), result.warnings);
+    }
+
+    #[test]
+    fn spoof_player_version() {
+        let result = read_preferences("spoof_player_version = \"32.0.0.465\"");
+        assert_eq!(
+            &SavedGlobalPreferences {
+                spoof_player_version: Some((32, 0, 0, 465)),
+                ..Default::default()
+            },
+            result.values()
+        );
+        assert_eq!(Vec::<String>::new(), result.warnings);
+
+        let result = read_preferences("spoof_player_version = \"32.0\"");
+        assert_eq!(&SavedGlobalPreferences::default(), result.values());
+        assert_eq!(
+            vec!["Invalid spoof_player_version: unsupported value \"32.0\"".to_string()],
+            result.warnings
+        );
+
+        let result = read_preferences("spoof_player_version = \"32.0.0.465.1\"");
+        assert_eq!(&SavedGlobalPreferences::default(), result.values());
+        assert_eq!(
+            vec!["Invalid spoof_player_version: unsupported value \"32.0.0.465.1\"".to_string()],
+            result.warnings
+        );
+
+        let result = read_preferences("spoof_player_version = 32");
+        assert_eq!(&SavedGlobalPreferences::default(), result.values());
+        assert_eq!(
+            vec!["Invalid spoof_player_version: expected string but found integer".to_string()],
+            result.warnings
+        );
+    }
+
+    #[test]
+    fn as_api_version() {
+        let result = read_preferences("as_api_version = 12");
+        assert_eq!(
+            &SavedGlobalPreferences {
+                as_api_version: Some(12),
+                ..Default::default()
+            },
+            result.values()
+        );
+        assert_eq!(Vec::<String>::new(), result.warnings);
+
+        let result = read_preferences("as_api_version = 70000");
+        assert_eq!(&SavedGlobalPreferences::default(), result.values());
+        assert_eq!(
+            vec!["Invalid as_api_version: 70000 is out of range".to_string()],
+            result.warnings
+        );
+
+        let result = read_preferences("as_api_version = 999");
+        assert_eq!(&SavedGlobalPreferences::default(), result.values());
+        assert_eq!(
+            vec!["Invalid as_api_version: unsupported value 999".to_string()],
+            result.warnings
+        );
+    }
+
+    #[test]
+    fn hide_cursor_after_ms() {
+        let result = read_preferences("hide_cursor_after_ms = 0");
+        assert_eq!(
+            &SavedGlobalPreferences {
+                hide_cursor_after_ms: Some(0),
+                ..Default::default()
+            },
+            result.values()
+        );
+        assert_eq!(Vec::<String>::new(), result.warnings);
+
+        let result = read_preferences("hide_cursor_after_ms = 2500");
+        assert_eq!(
+            &SavedGlobalPreferences {
+                hide_cursor_after_ms: Some(2500),
+                ..Default::default()
+            },
+            result.values()
+        );
+        assert_eq!(Vec::<String>::new(), result.warnings);
+
+        let result = read_preferences("hide_cursor_after_ms = -1");
+        assert_eq!(&SavedGlobalPreferences::default(), result.values());
+        assert_eq!(
+            vec!["Invalid hide_cursor_after_ms: -1 is out of range".to_string()],
+            result.warnings
+        );
+
+        let result = read_preferences("hide_cursor_after_ms = \"never\"");
+        assert_eq!(&SavedGlobalPreferences::default(), result.values());
+        assert_eq!(
+            vec!["Invalid hide_cursor_after_ms: expected integer but found string".to_string()],
+            result.warnings
+        );
+    }
+
+    #[test]
+    fn max_execution_duration_secs() {
+        let result = read_preferences("max_execution_duration_secs = 15.0");
+        assert_eq!(
+            &SavedGlobalPreferences {
+                max_execution_duration_secs: Some(15.0),
+                ..Default::default()
+            },
+            result.values()
+        );
+        assert_eq!(Vec::<String>::new(), result.warnings);
+
+        let result = read_preferences("max_execution_duration_secs = -1.0");
+        assert_eq!(&SavedGlobalPreferences::default(), result.values());
+        assert_eq!(
+            vec!["Invalid max_execution_duration_secs: -1 is not a positive duration".to_string()],
+            result.warnings
+        );
+
+        let result = read_preferences("");
+        assert_eq!(None, result.values().max_execution_duration_secs);
+        assert_eq!(Vec::<String>::new(), result.warnings);
+    }
+
+    #[test]
+    fn max_content_memory_mb() {
+        let result = read_preferences("max_content_memory_mb = 512");
+        assert_eq!(
+            &SavedGlobalPreferences {
+                max_content_memory_mb: Some(512),
+                ..Default::default()
+            },
+            result.values()
+        );
+        assert_eq!(Vec::<String>::new(), result.warnings);
+
+        let result = read_preferences("max_content_memory_mb = 1");
+        assert_eq!(&SavedGlobalPreferences::default(), result.values());
+        assert_eq!(
+            vec!["Invalid max_content_memory_mb: 1 is out of range (minimum 16)".to_string()],
+            result.warnings
+        );
+
+        let result = read_preferences("max_content_memory_mb = \"lots\"");
+        assert_eq!(&SavedGlobalPreferences::default(), result.values());
+        assert_eq!(
+            vec!["Invalid max_content_memory_mb: expected integer but found string".to_string()],
+            result.warnings
+        );
+    }
+
+    #[test]
+    fn max_texture_size() {
+        let result = read_preferences("max_texture_size = 4096");
+        assert_eq!(
+            &SavedGlobalPreferences {
+                max_texture_size: Some(4096),
+                ..Default::default()
+            },
+            result.values()
+        );
+        assert_eq!(Vec::<String>::new(), result.warnings);
+
+        for invalid in ["3000", "128", "-4096"] {
+            let result = read_preferences(&format!("max_texture_size = {invalid}"));
+            assert_eq!(&SavedGlobalPreferences::default(), result.values());
+            assert_eq!(
+                vec![format!(
+                    "Invalid max_texture_size: {invalid} is not a power of two of at least 256"
+                )],
+                result.warnings
+            );
+        }
+    }
+
+    #[test]
+    fn max_frame_rate() {
+        let result = read_preferences("max_frame_rate = 60");
+        assert_eq!(
+            &SavedGlobalPreferences {
+                max_frame_rate: Some(60),
+                ..Default::default()
+            },
+            result.values()
+        );
+        assert_eq!(Vec::<String>::new(), result.warnings);
+
+        let result = read_preferences("max_frame_rate = 70000");
+        assert_eq!(&SavedGlobalPreferences::default(), result.values());
+        assert_eq!(
+            vec!["Invalid max_frame_rate: 70000 is out of range".to_string()],
+            result.warnings
+        );
+    }
+
+    #[test]
+    fn input_poll_hz() {
+        for hz in [10, 250, 1000] {
+            let result = read_preferences(&format!("input_poll_hz = {hz}"));
+            assert_eq!(
+                &SavedGlobalPreferences {
+                    input_poll_hz: Some(hz),
+                    ..Default::default()
+                },
+                result.values()
+            );
+            assert_eq!(Vec::<String>::new(), result.warnings);
+        }
+
+        for invalid in ["9", "1001", "-60"] {
+            let result = read_preferences(&format!("input_poll_hz = {invalid}"));
+            assert_eq!(&SavedGlobalPreferences::default(), result.values());
+            assert_eq!(
+                vec![format!(
+                    "Invalid input_poll_hz: {invalid} is out of range (10 to 1000)"
+                )],
+                result.warnings
+            );
+        }
+    }
+
+    #[test]
+    fn network_rules() {
+        let result = read_preferences(
+            r#"
+            [[network_rule]]
+            pattern = "https://example.com/*"
+            allow = true
+
+            [[network_rule]]
+            pattern = "*"
+            allow = false
+            "#,
+        );
+        assert_eq!(
+            &SavedGlobalPreferences {
+                network_rules: vec![
+                    NetworkRule {
+                        pattern: "https://example.com/*".to_string(),
+                        allow: true,
+                    },
+                    NetworkRule {
+                        pattern: "*".to_string(),
+                        allow: false,
+                    },
+                ],
+                ..Default::default()
+            },
+            result.values()
+        );
+        assert_eq!(Vec::<String>::new(), result.warnings);
+
+        let result = read_preferences(
+            r#"
+            [[network_rule]]
+            allow = true
+
+            [[network_rule]]
+            pattern = "*"
+            "#,
+        );
+        assert_eq!(
+            &SavedGlobalPreferences {
+                network_rules: vec![NetworkRule {
+                    pattern: "*".to_string(),
+                    allow: false,
+                }],
+                ..Default::default()
+            },
+            result.values()
+        );
+        assert_eq!(
+            vec!["Invalid network_rule: missing pattern".to_string()],
+            result.warnings
+        );
+
+        let result = read_preferences("network_rule = \"*\"");
+        assert_eq!(&SavedGlobalPreferences::default(), result.values());
+        assert_eq!(
+            vec!["Invalid network_rule: expected array of tables but found string".to_string()],
+            result.warnings
+        );
+    }
+
+    #[test]
+    fn movie_zoom() {
+        let result =
+            read_preferences("[movie_zoom]\n\"/movies/a.swf\" = 1.5\n\"/movies/b.swf\" = 2.0\n");
+        assert_eq!(
+            &SavedGlobalPreferences {
+                movie_zoom: HashMap::from([
+                    ("/movies/a.swf".to_string(), 1.5),
+                    ("/movies/b.swf".to_string(), 2.0),
+                ]),
+                ..Default::default()
+            },
+            result.values()
+        );
+        assert_eq!(Vec::<String>::new(), result.warnings);
+
+        let result = read_preferences(
+            "[movie_zoom]\n\"/movies/a.swf\" = 100.0\n\"/movies/b.swf\" = \"big\"\n\"/movies/c.swf\" = 0.5\n",
+        );
+        assert_eq!(
+            &SavedGlobalPreferences {
+                movie_zoom: HashMap::from([("/movies/c.swf".to_string(), 0.5)]),
+                ..Default::default()
+            },
+            result.values()
+        );
+        assert_eq!(
+            vec![
+                "Invalid movie_zoom./movies/a.swf: zoom factor 100 is out of range".to_string(),
+                "Invalid movie_zoom./movies/b.swf: expected float but found string".to_string(),
+            ],
+            result.warnings
+        );
+    }
+
+    #[test]
+    fn default_main_swf() {
+        let result = read_preferences(
+            "[default_main_swf]\n\"/games/a.zip\" = \"main.swf\"\n\"/games/b\" = \"game/start.swf\"\n",
+        );
+        assert_eq!(
+            &SavedGlobalPreferences {
+                default_main_swf: HashMap::from([
+                    ("/games/a.zip".to_string(), "main.swf".to_string()),
+                    ("/games/b".to_string(), "game/start.swf".to_string()),
+                ]),
+                ..Default::default()
+            },
+            result.values()
+        );
+        assert_eq!(Vec::<String>::new(), result.warnings);
+
+        let result = read_preferences(
+            "[default_main_swf]\n\"/games/a.zip\" = 1\n\"/games/b\" = \"start.swf\"\n",
+        );
+        assert_eq!(
+            &SavedGlobalPreferences {
+                default_main_swf: HashMap::from([(
+                    "/games/b".to_string(),
+                    "start.swf".to_string()
+                )]),
+                ..Default::default()
+            },
+            result.values()
+        );
+        assert_eq!(
+            vec![
+                "Invalid default_main_swf./games/a.zip: expected string but found integer"
+                    .to_string()
+            ],
+            result.warnings
+        );
+    }
+
+    #[test]
+    fn log() {
+        let result = read_preferences("log = \"yes\"");
+        assert_eq!(&SavedGlobalPreferences::default(), result.values());
+        assert_eq!(
+            vec!["Invalid log: expected table but found string".to_string()],
+            result.warnings
+        );
+    }
+
+    #[test]
+    fn storage_backend() {
+        let result = read_preferences("storage = {backend = 5}");
+        assert_eq!(&SavedGlobalPreferences::default(), result.values());
+        assert_eq!(
+            vec!["Invalid storage.backend: expected string but found integer".to_string()],
+            result.warnings
+        );
+
+        let result = read_preferences("storage = {backend = \"???\"}");
+        assert_eq!(&SavedGlobalPreferences::default(), result.values());
+        assert_eq!(
+            vec!["Invalid storage.backend: unsupported value \"???\"".to_string()],
+            result.warnings
+        );
+
+        let result = read_preferences("storage = {backend = \"memory\"}");
+        assert_eq!(
+            &SavedGlobalPreferences {
+                storage: StoragePreferences {
+                    backend: StorageBackend::Memory,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            result.values()
+        );
+        assert_eq!(Vec::<String>::new(), result.warnings);
+    }
+
+    #[test]
+    fn storage_per_domain_overrides() {
+        let result = read_preferences(
+            "[storage.per_domain_overrides]\n\"example.com\" = \"memory\"\n\"ruffle.rs\" = \"disk\"\n",
+        );
+        assert_eq!(
+            &SavedGlobalPreferences {
+                storage: StoragePreferences {
+                    per_domain_overrides: HashMap::from([
+                        ("example.com".to_string(), StorageBackend::Memory),
+                        ("ruffle.rs".to_string(), StorageBackend::Disk),
+                    ]),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            result.values()
+        );
+        assert_eq!(Vec::<String>::new(), result.warnings);
+
+        let result = read_preferences(
+            "[storage.per_domain_overrides]\n\"example.com\" = \"???\"\n\"ruffle.rs\" = 5\n",
+        );
+        assert_eq!(&SavedGlobalPreferences::default(), result.values());
+        assert_eq!(
+            vec![
+                "Invalid storage.per_domain_overrides.example.com: unsupported value \"???\""
+                    .to_string(),
+                "Invalid storage.per_domain_overrides.ruffle.rs: expected string but found integer"
+                    .to_string()
+            ],
+            result.warnings
+        );
     }
 
     #[test]
@@ -1,11 +1,29 @@
-use crate::log::FilenamePattern;
+use crate::gui::available_languages;
+use crate::log::{FilenamePattern, LogLevel};
 use crate::preferences::storage::StorageBackend;
-use crate::preferences::SavedGlobalPreferences;
+use crate::preferences::{
+    AllowDenyAsk, AmfEncoding, AudioChannels, FontAntialias, NetworkRule, PreferencesError,
+    SavedGlobalPreferences, UncaughtErrorBehavior, INPUT_POLL_HZ_RANGE, MIN_CONTENT_MEMORY_MB,
+    MIN_TEXTURE_SIZE, MOVIE_ZOOM_RANGE,
+};
+use clap::ValueEnum;
 use ruffle_frontend_utils::parse::DocumentHolder;
+use ruffle_frontend_utils::write::TableExt;
 use ruffle_render_wgpu::clap::{GraphicsBackend, PowerPreference};
-use toml_edit::value;
+use std::path::PathBuf;
+use toml_edit::{table, value, Table};
 use unic_langid::LanguageIdentifier;
 
+/// List every graphics backend that can be passed to
+/// `PreferencesWriter::set_graphics_backend`, along with the identifier it is
+/// saved as.
+#[allow(dead_code)]
+pub fn graphics_backends() -> impl Iterator<Item = (GraphicsBackend, &'static str)> {
+    GraphicsBackend::value_variants()
+        .iter()
+        .map(|backend| (*backend, backend.as_str()))
+}
+
 pub struct PreferencesWriter<'a>(&'a mut DocumentHolder<SavedGlobalPreferences>);
 
 impl<'a> PreferencesWriter<'a> {
@@ -27,6 +45,18 @@ impl<'a> PreferencesWriter<'a> {
         })
     }
 
+    #[allow(dead_code)]
+    pub fn set_max_feature_level(&mut self, level: Option<String>) {
+        self.0.edit(|values, toml_document| {
+            if let Some(level) = &level {
+                toml_document["max_feature_level"] = value(level);
+            } else {
+                toml_document.remove("max_feature_level");
+            }
+            values.max_feature_level = level;
+        })
+    }
+
     pub fn set_language(&mut self, language: LanguageIdentifier) {
         self.0.edit(|values, toml_document| {
             toml_document["language"] = value(language.to_string());
@@ -34,6 +64,32 @@ impl<'a> PreferencesWriter<'a> {
         })
     }
 
+    /// Set the language, refusing any that Ruffle has no translations for.
+    #[allow(dead_code)]
+    pub fn try_set_language(
+        &mut self,
+        language: LanguageIdentifier,
+    ) -> Result<(), PreferencesError> {
+        if !available_languages().contains(&&language) {
+            return Err(PreferencesError::UnsupportedLanguage(language));
+        }
+
+        self.set_language(language);
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub fn set_keyboard_layout(&mut self, layout: Option<String>) {
+        self.0.edit(|values, toml_document| {
+            if let Some(layout) = &layout {
+                toml_document["keyboard_layout"] = value(layout);
+            } else {
+                toml_document.remove("keyboard_layout");
+            }
+            values.keyboard_layout = layout;
+        })
+    }
+
     pub fn set_output_device(&mut self, name: Option<String>) {
         self.0.edit(|values, toml_document| {
             if let Some(name) = &name {
@@ -45,6 +101,30 @@ impl<'a> PreferencesWriter<'a> {
         })
     }
 
+    #[allow(dead_code)]
+    pub fn set_last_open_dir(&mut self, dir: Option<PathBuf>) {
+        self.0.edit(|values, toml_document| {
+            if let Some(dir) = &dir {
+                toml_document["last_open_dir"] = value(dir.to_string_lossy().as_ref());
+            } else {
+                toml_document.remove("last_open_dir");
+            }
+            values.last_open_dir = dir;
+        })
+    }
+
+    #[allow(dead_code)]
+    pub fn set_patches_dir(&mut self, dir: Option<PathBuf>) {
+        self.0.edit(|values, toml_document| {
+            if let Some(dir) = &dir {
+                toml_document["patches_dir"] = value(dir.to_string_lossy().as_ref());
+            } else {
+                toml_document.remove("patches_dir");
+            }
+            values.patches_dir = dir;
+        })
+    }
+
     pub fn set_mute(&mut self, mute: bool) {
         self.0.edit(|values, toml_document| {
             toml_document["mute"] = value(mute);
@@ -52,6 +132,114 @@ impl<'a> PreferencesWriter<'a> {
         })
     }
 
+    #[allow(dead_code)]
+    pub fn set_loop_video_by_default(&mut self, loop_video: bool) {
+        self.0.edit(|values, toml_document| {
+            toml_document["loop_video_by_default"] = value(loop_video);
+            values.loop_video_by_default = loop_video;
+        })
+    }
+
+    #[allow(dead_code)]
+    pub fn set_default_shared_object_encoding(&mut self, encoding: AmfEncoding) {
+        self.0.edit(|values, toml_document| {
+            toml_document["default_shared_object_encoding"] = value(encoding.as_str());
+            values.default_shared_object_encoding = encoding;
+        })
+    }
+
+    #[allow(dead_code)]
+    pub fn set_rng_seed(&mut self, seed: Option<u64>) {
+        self.0.edit(|values, toml_document| {
+            if let Some(seed) = seed {
+                toml_document["rng_seed"] = value(seed.to_string());
+            } else {
+                toml_document.remove("rng_seed");
+            }
+            values.rng_seed = seed;
+        })
+    }
+
+    #[allow(dead_code)]
+    pub fn set_start_paused(&mut self, start_paused: bool) {
+        self.0.edit(|values, toml_document| {
+            toml_document["start_paused"] = value(start_paused);
+            values.start_paused = start_paused;
+        })
+    }
+
+    #[allow(dead_code)]
+    pub fn set_default_font_antialias(&mut self, antialias: FontAntialias) {
+        self.0.edit(|values, toml_document| {
+            toml_document["default_font_antialias"] = value(antialias.as_str());
+            values.default_font_antialias = antialias;
+        })
+    }
+
+    #[allow(dead_code)]
+    pub fn set_enable_ime(&mut self, enable_ime: bool) {
+        self.0.edit(|values, toml_document| {
+            toml_document["enable_ime"] = value(enable_ime);
+            values.enable_ime = enable_ime;
+        })
+    }
+
+    #[allow(dead_code)]
+    pub fn set_camera_microphone_permission(&mut self, permission: AllowDenyAsk) {
+        self.0.edit(|values, toml_document| {
+            toml_document["camera_microphone_permission"] = value(permission.as_str());
+            values.camera_microphone_permission = permission;
+        })
+    }
+
+    #[allow(dead_code)]
+    pub fn set_allow_fullscreen_request(&mut self, permission: AllowDenyAsk) {
+        self.0.edit(|values, toml_document| {
+            toml_document["allow_fullscreen_request"] = value(permission.as_str());
+            values.allow_fullscreen_request = permission;
+        })
+    }
+
+    #[allow(dead_code)]
+    pub fn set_show_ruffle_context_menu_items(&mut self, show: bool) {
+        self.0.edit(|values, toml_document| {
+            toml_document["show_ruffle_context_menu_items"] = value(show);
+            values.show_ruffle_context_menu_items = show;
+        })
+    }
+
+    #[allow(dead_code)]
+    pub fn set_verbose_avm2_verification(&mut self, verbose: bool) {
+        self.0.edit(|values, toml_document| {
+            toml_document["verbose_avm2_verification"] = value(verbose);
+            values.verbose_avm2_verification = verbose;
+        })
+    }
+
+    #[allow(dead_code)]
+    pub fn set_strict_avm_errors(&mut self, strict: bool) {
+        self.0.edit(|values, toml_document| {
+            toml_document["strict_avm_errors"] = value(strict);
+            values.strict_avm_errors = strict;
+        })
+    }
+
+    #[allow(dead_code)]
+    pub fn set_uncaught_error_behavior(&mut self, behavior: UncaughtErrorBehavior) {
+        self.0.edit(|values, toml_document| {
+            toml_document["uncaught_error_behavior"] = value(behavior.as_str());
+            values.uncaught_error_behavior = behavior;
+        })
+    }
+
+    #[allow(dead_code)]
+    pub fn set_auto_reload_on_change(&mut self, auto_reload_on_change: bool) {
+        self.0.edit(|values, toml_document| {
+            toml_document["auto_reload_on_change"] = value(auto_reload_on_change);
+            values.auto_reload_on_change = auto_reload_on_change;
+        })
+    }
+
     pub fn set_volume(&mut self, volume: f32) {
         self.0.edit(|values, toml_document| {
             toml_document["volume"] = value(volume as f64);
@@ -59,6 +247,204 @@ impl<'a> PreferencesWriter<'a> {
         })
     }
 
+    #[allow(dead_code)]
+    pub fn set_audio_channels(&mut self, channels: AudioChannels) {
+        self.0.edit(|values, toml_document| {
+            toml_document["audio_channels"] = value(channels.as_str());
+            values.audio_channels = channels;
+        })
+    }
+
+    #[allow(dead_code)]
+    pub fn set_spoof_player_version(&mut self, version: Option<(u8, u8, u16, u16)>) {
+        self.0.edit(|values, toml_document| {
+            if let Some((major, minor, build, revision)) = version {
+                toml_document["spoof_player_version"] =
+                    value(format!("{major}.{minor}.{build}.{revision}"));
+            } else {
+                toml_document.remove("spoof_player_version");
+            }
+            values.spoof_player_version = version;
+        })
+    }
+
+    #[allow(dead_code)]
+    pub fn set_as_api_version(&mut self, version: Option<u16>) {
+        self.0.edit(|values, toml_document| {
+            if let Some(version) = version {
+                toml_document["as_api_version"] = value(version as i64);
+            } else {
+                toml_document.remove("as_api_version");
+            }
+            values.as_api_version = version;
+        })
+    }
+
+    #[allow(dead_code)]
+    pub fn set_hide_cursor_after_ms(&mut self, ms: Option<u32>) {
+        self.0.edit(|values, toml_document| {
+            if let Some(ms) = ms {
+                toml_document["hide_cursor_after_ms"] = value(ms as i64);
+            } else {
+                toml_document.remove("hide_cursor_after_ms");
+            }
+            values.hide_cursor_after_ms = ms;
+        })
+    }
+
+    #[allow(dead_code)]
+    pub fn set_max_execution_duration_secs(&mut self, secs: Option<f64>) {
+        self.0.edit(|values, toml_document| {
+            if let Some(secs) = secs {
+                toml_document["max_execution_duration_secs"] = value(secs);
+            } else {
+                toml_document.remove("max_execution_duration_secs");
+            }
+            values.max_execution_duration_secs = secs;
+        })
+    }
+
+    #[allow(dead_code)]
+    pub fn set_max_content_memory_mb(&mut self, megabytes: Option<u32>) {
+        let megabytes = megabytes.map(|megabytes| megabytes.max(MIN_CONTENT_MEMORY_MB));
+        self.0.edit(|values, toml_document| {
+            if let Some(megabytes) = megabytes {
+                toml_document["max_content_memory_mb"] = value(megabytes as i64);
+            } else {
+                toml_document.remove("max_content_memory_mb");
+            }
+            values.max_content_memory_mb = megabytes;
+        })
+    }
+
+    /// Sizes that aren't a power of two are rounded down to one.
+    #[allow(dead_code)]
+    pub fn set_max_texture_size(&mut self, size: Option<u32>) {
+        let size = size.map(|size| {
+            let size = size.max(MIN_TEXTURE_SIZE);
+            1 << (u32::BITS - 1 - size.leading_zeros())
+        });
+        self.0.edit(|values, toml_document| {
+            if let Some(size) = size {
+                toml_document["max_texture_size"] = value(size as i64);
+            } else {
+                toml_document.remove("max_texture_size");
+            }
+            values.max_texture_size = size;
+        })
+    }
+
+    #[allow(dead_code)]
+    pub fn set_max_frame_rate(&mut self, frame_rate: Option<u16>) {
+        self.0.edit(|values, toml_document| {
+            if let Some(frame_rate) = frame_rate {
+                toml_document["max_frame_rate"] = value(frame_rate as i64);
+            } else {
+                toml_document.remove("max_frame_rate");
+            }
+            values.max_frame_rate = frame_rate;
+        })
+    }
+
+    /// Rates outside of `INPUT_POLL_HZ_RANGE` are clamped into it.
+    #[allow(dead_code)]
+    pub fn set_input_poll_hz(&mut self, hz: Option<u32>) {
+        let hz = hz.map(|hz| hz.clamp(*INPUT_POLL_HZ_RANGE.start(), *INPUT_POLL_HZ_RANGE.end()));
+        self.0.edit(|values, toml_document| {
+            if let Some(hz) = hz {
+                toml_document["input_poll_hz"] = value(hz as i64);
+            } else {
+                toml_document.remove("input_poll_hz");
+            }
+            values.input_poll_hz = hz;
+        })
+    }
+
+    #[allow(dead_code)]
+    pub fn push_network_rule(&mut self, rule: NetworkRule) {
+        self.0.edit(|values, toml_document| {
+            let mut rule_table = Table::new();
+            rule_table["pattern"] = value(&rule.pattern);
+            rule_table["allow"] = value(rule.allow);
+            toml_document
+                .get_or_create_array_of_tables("network_rule")
+                .push(rule_table);
+            values.network_rules.push(rule);
+        })
+    }
+
+    #[allow(dead_code)]
+    pub fn clear_network_rules(&mut self) {
+        self.0.edit(|values, toml_document| {
+            toml_document.remove("network_rule");
+            values.network_rules.clear();
+        })
+    }
+
+    #[allow(dead_code)]
+    pub fn set_movie_zoom(&mut self, path: String, factor: f32) {
+        let factor = factor.clamp(*MOVIE_ZOOM_RANGE.start(), *MOVIE_ZOOM_RANGE.end());
+        self.0.edit(|values, toml_document| {
+            if toml_document.get("movie_zoom").is_none() {
+                toml_document["movie_zoom"] = table();
+            }
+            toml_document["movie_zoom"][&path] = value(factor as f64);
+            values.movie_zoom.insert(path, factor);
+        })
+    }
+
+    #[allow(dead_code)]
+    pub fn clear_movie_zoom(&mut self, path: &str) {
+        self.0.edit(|values, toml_document| {
+            if let Some(movie_zoom) = toml_document
+                .get_mut("movie_zoom")
+                .and_then(|movie_zoom| movie_zoom.as_table_like_mut())
+            {
+                movie_zoom.remove(path);
+            }
+            values.movie_zoom.remove(path);
+        })
+    }
+
+    #[allow(dead_code)]
+    pub fn set_default_main_swf(&mut self, archive: String, entry: String) {
+        self.0.edit(|values, toml_document| {
+            if toml_document.get("default_main_swf").is_none() {
+                toml_document["default_main_swf"] = table();
+            }
+            toml_document["default_main_swf"][&archive] = value(&entry);
+            values.default_main_swf.insert(archive, entry);
+        })
+    }
+
+    #[allow(dead_code)]
+    pub fn clear_default_main_swf(&mut self, archive: &str) {
+        self.0.edit(|values, toml_document| {
+            if let Some(default_main_swf) = toml_document
+                .get_mut("default_main_swf")
+                .and_then(|default_main_swf| default_main_swf.as_table_like_mut())
+            {
+                default_main_swf.remove(archive);
+            }
+            values.default_main_swf.remove(archive);
+        })
+    }
+
+    #[allow(dead_code)]
+    pub fn set_audio_sample_rate(&mut self, rate: Option<u32>) {
+        self.0.edit(|values, toml_document| {
+            if let Some(rate) = rate {
+                toml_document["audio"]["sample_rate"] = value(rate as i64);
+            } else if let Some(audio) = toml_document
+                .get_mut("audio")
+                .and_then(|audio| audio.as_table_like_mut())
+            {
+                audio.remove("sample_rate");
+            }
+            values.audio.sample_rate = rate;
+        })
+    }
+
     pub fn set_log_filename_pattern(&mut self, pattern: FilenamePattern) {
         self.0.edit(|values, toml_document| {
             toml_document["log"]["filename_pattern"] = value(pattern.as_str());
@@ -66,12 +452,54 @@ impl<'a> PreferencesWriter<'a> {
         })
     }
 
+    #[allow(dead_code)]
+    pub fn set_log_level(&mut self, level: LogLevel) {
+        self.0.edit(|values, toml_document| {
+            toml_document["log"]["level"] = value(level.as_str());
+            values.log.level = level;
+        })
+    }
+
+    pub fn set_log_enabled(&mut self, enabled: bool) {
+        self.0.edit(|values, toml_document| {
+            toml_document["log"]["enabled"] = value(enabled);
+            values.log.enabled = enabled;
+        })
+    }
+
     pub fn set_storage_backend(&mut self, backend: StorageBackend) {
         self.0.edit(|values, toml_document| {
             toml_document["storage"]["backend"] = value(backend.as_str());
             values.storage.backend = backend;
         })
     }
+
+    #[allow(dead_code)]
+    pub fn set_domain_storage_backend(&mut self, domain: String, backend: StorageBackend) {
+        self.0.edit(|values, toml_document| {
+            let storage = &mut toml_document["storage"];
+            if storage.is_table() && storage.get("per_domain_overrides").is_none() {
+                // Keep an explicit `[storage]` table explicit, rather than nesting an inline table.
+                storage["per_domain_overrides"] = table();
+            }
+            storage["per_domain_overrides"][&domain] = value(backend.as_str());
+            values.storage.per_domain_overrides.insert(domain, backend);
+        })
+    }
+
+    #[allow(dead_code)]
+    pub fn clear_domain_storage_backend(&mut self, domain: &str) {
+        self.0.edit(|values, toml_document| {
+            if let Some(overrides) = toml_document
+                .get_mut("storage")
+                .and_then(|storage| storage.get_mut("per_domain_overrides"))
+                .and_then(|overrides| overrides.as_table_like_mut())
+            {
+                overrides.remove(domain);
+            }
+            values.storage.per_domain_overrides.remove(domain);
+        })
+    }
 }
 
 #[cfg(test)]
@@ -101,6 +529,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn graphics_backends() {
+        let backends: Vec<_> = super::graphics_backends().collect();
+        assert_eq!(
+            vec!["default", "vulkan", "metal", "dx12", "gl"],
+            backends.iter().map(|(_, name)| *name).collect::<Vec<_>>()
+        );
+
+        for (backend, name) in backends {
+            test(
+                "",
+                |writer| writer.set_graphics_backend(backend),
+                &format!("graphics_backend = \"{name}\"\n"),
+            );
+        }
+    }
+
     #[test]
     fn set_graphics_power_preference() {
         test(
@@ -116,6 +561,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn set_max_feature_level() {
+        test(
+            "",
+            |writer| writer.set_max_feature_level(Some("downlevel".to_string())),
+            "max_feature_level = \"downlevel\"\n",
+        );
+        test(
+            "max_feature_level = \"downlevel\"",
+            |writer| writer.set_max_feature_level(None),
+            "",
+        );
+    }
+
     #[test]
     fn set_language() {
         test(
@@ -131,6 +590,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn try_set_language() {
+        let mut preferences = read_preferences("language = \"en-US\"").result;
+        let mut writer = PreferencesWriter::new(&mut preferences);
+        assert_eq!(Ok(()), writer.try_set_language(langid!("fr-FR")));
+        assert_eq!(
+            Err(PreferencesError::UnsupportedLanguage(langid!("xx-XX"))),
+            writer.try_set_language(langid!("xx-XX"))
+        );
+        assert_eq!(langid!("fr-FR"), preferences.language);
+        assert_eq!("language = \"fr-FR\"\n", preferences.serialize());
+    }
+
+    #[test]
+    fn set_keyboard_layout() {
+        test(
+            "",
+            |writer| writer.set_keyboard_layout(Some("azerty".to_string())),
+            "keyboard_layout = \"azerty\"\n",
+        );
+        test(
+            "keyboard_layout = \"azerty\"",
+            |writer| writer.set_keyboard_layout(None),
+            "",
+        );
+    }
+
     #[test]
     fn set_output_device() {
         test(
@@ -146,11 +632,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn set_last_open_dir() {
+        test(
+            "",
+            |writer| writer.set_last_open_dir(Some(PathBuf::from("/home/user/swfs"))),
+            "last_open_dir = \"/home/user/swfs\"\n",
+        );
+        test(
+            "last_open_dir = \"/home/user/swfs\"",
+            |writer| writer.set_last_open_dir(None),
+            "",
+        );
+    }
+
+    #[test]
+    fn set_patches_dir() {
+        test(
+            "",
+            |writer| writer.set_patches_dir(Some(PathBuf::from("/home/user/ruffle patches"))),
+            "patches_dir = \"/home/user/ruffle patches\"\n",
+        );
+        test(
+            "patches_dir = \"/home/user/ruffle patches\"",
+            |writer| writer.set_patches_dir(None),
+            "",
+        );
+    }
+
     #[test]
     fn set_volume() {
         test("", |writer| writer.set_volume(0.5), "volume = 0.5\n");
     }
 
+    #[test]
+    fn set_audio_channels() {
+        for channels in [
+            AudioChannels::Auto,
+            AudioChannels::Mono,
+            AudioChannels::Stereo,
+        ] {
+            test(
+                "audio_channels = \"auto\"",
+                |writer| writer.set_audio_channels(channels),
+                &format!("audio_channels = \"{}\"\n", channels.as_str()),
+            );
+        }
+    }
+
     #[test]
     fn set_mute() {
         test("", |writer| writer.set_mute(true), "mute = true\n");
@@ -162,40 +691,606 @@ mod tests {
     }
 
     #[test]
-    fn set_log_filename_pattern() {
+    fn set_loop_video_by_default() {
         test(
             "",
-            |writer| writer.set_log_filename_pattern(FilenamePattern::WithTimestamp),
-            "log = { filename_pattern = \"with_timestamp\" }\n",
+            |writer| writer.set_loop_video_by_default(true),
+            "loop_video_by_default = true\n",
         );
         test(
-            "log = { filename_pattern = \"with_timestamp\" }\n",
-            |writer| writer.set_log_filename_pattern(FilenamePattern::SingleFile),
-            "log = { filename_pattern = \"single_file\" }\n",
+            "loop_video_by_default = true",
+            |writer| writer.set_loop_video_by_default(false),
+            "loop_video_by_default = false\n",
         );
+    }
+
+    #[test]
+    fn set_default_shared_object_encoding() {
+        for encoding in [AmfEncoding::Amf0, AmfEncoding::Amf3] {
+            test(
+                "",
+                |writer| writer.set_default_shared_object_encoding(encoding),
+                &format!(
+                    "default_shared_object_encoding = \"{}\"\n",
+                    encoding.as_str()
+                ),
+            );
+        }
         test(
-            "[log]\nfilename_pattern = \"with_timestamp\"\n",
-            |writer| writer.set_log_filename_pattern(FilenamePattern::SingleFile),
-            "[log]\nfilename_pattern = \"single_file\"\n",
+            "default_shared_object_encoding = \"amf0\"\n",
+            |writer| writer.set_default_shared_object_encoding(AmfEncoding::Amf3),
+            "default_shared_object_encoding = \"amf3\"\n",
         );
     }
 
     #[test]
-    fn set_storage_backend() {
+    fn set_rng_seed() {
         test(
             "",
-            |writer| writer.set_storage_backend(StorageBackend::Disk),
-            "storage = { backend = \"disk\" }\n",
+            |writer| writer.set_rng_seed(Some(42)),
+            "rng_seed = \"42\"\n",
         );
         test(
-            "storage = { backend = \"disk\" }\n",
-            |writer| writer.set_storage_backend(StorageBackend::Memory),
-            "storage = { backend = \"memory\" }\n",
+            "rng_seed = \"42\"",
+            |writer| writer.set_rng_seed(Some(u64::MAX)),
+            "rng_seed = \"18446744073709551615\"\n",
         );
         test(
-            "[storage]\nbackend = \"disk\"\n",
-            |writer| writer.set_storage_backend(StorageBackend::Memory),
-            "[storage]\nbackend = \"memory\"\n",
+            "rng_seed = \"18446744073709551615\"",
+            |writer| writer.set_rng_seed(None),
+            "",
+        );
+    }
+
+    #[test]
+    fn set_start_paused() {
+        test(
+            "",
+            |writer| writer.set_start_paused(true),
+            "start_paused = true\n",
+        );
+        test(
+            "mute = true\nstart_paused = true\n",
+            |writer| writer.set_start_paused(false),
+            "mute = true\nstart_paused = false\n",
+        );
+    }
+
+    #[test]
+    fn set_default_font_antialias() {
+        test(
+            "",
+            |writer| writer.set_default_font_antialias(FontAntialias::Normal),
+            "default_font_antialias = \"normal\"\n",
+        );
+        test(
+            "default_font_antialias = \"normal\"",
+            |writer| writer.set_default_font_antialias(FontAntialias::Advanced),
+            "default_font_antialias = \"advanced\"\n",
+        );
+    }
+
+    #[test]
+    fn set_enable_ime() {
+        test(
+            "",
+            |writer| writer.set_enable_ime(false),
+            "enable_ime = false\n",
+        );
+        test(
+            "enable_ime = false",
+            |writer| writer.set_enable_ime(true),
+            "enable_ime = true\n",
+        );
+    }
+
+    #[test]
+    fn set_camera_microphone_permission() {
+        for permission in [AllowDenyAsk::Allow, AllowDenyAsk::Deny, AllowDenyAsk::Ask] {
+            test(
+                "",
+                |writer| writer.set_camera_microphone_permission(permission),
+                &format!(
+                    "camera_microphone_permission = \"{}\"\n",
+                    permission.as_str()
+                ),
+            );
+        }
+    }
+
+    #[test]
+    fn set_allow_fullscreen_request() {
+        for permission in [AllowDenyAsk::Allow, AllowDenyAsk::Deny, AllowDenyAsk::Ask] {
+            test(
+                "",
+                |writer| writer.set_allow_fullscreen_request(permission),
+                &format!("allow_fullscreen_request = \"{}\"\n", permission.as_str()),
+            );
+        }
+        test(
+            "allow_fullscreen_request = \"deny\"\n",
+            |writer| writer.set_allow_fullscreen_request(AllowDenyAsk::Allow),
+            "allow_fullscreen_request = \"allow\"\n",
+        );
+    }
+
+    #[test]
+    fn set_show_ruffle_context_menu_items() {
+        test(
+            "",
+            |writer| writer.set_show_ruffle_context_menu_items(false),
+            "show_ruffle_context_menu_items = false\n",
+        );
+        test(
+            "show_ruffle_context_menu_items = false",
+            |writer| writer.set_show_ruffle_context_menu_items(true),
+            "show_ruffle_context_menu_items = true\n",
+        );
+    }
+
+    #[test]
+    fn set_verbose_avm2_verification() {
+        test(
+            "",
+            |writer| writer.set_verbose_avm2_verification(true),
+            "verbose_avm2_verification = true\n",
+        );
+        test(
+            "verbose_avm2_verification = true",
+            |writer| writer.set_verbose_avm2_verification(false),
+            "verbose_avm2_verification = false\n",
+        );
+    }
+
+    #[test]
+    fn set_strict_avm_errors() {
+        test(
+            "",
+            |writer| writer.set_strict_avm_errors(true),
+            "strict_avm_errors = true\n",
+        );
+        test(
+            "strict_avm_errors = true",
+            |writer| writer.set_strict_avm_errors(false),
+            "strict_avm_errors = false\n",
+        );
+    }
+
+    #[test]
+    fn set_uncaught_error_behavior() {
+        for behavior in [
+            UncaughtErrorBehavior::Dialog,
+            UncaughtErrorBehavior::Log,
+            UncaughtErrorBehavior::Ignore,
+        ] {
+            test(
+                "",
+                |writer| writer.set_uncaught_error_behavior(behavior),
+                &format!("uncaught_error_behavior = \"{}\"\n", behavior.as_str()),
+            );
+        }
+        test(
+            "uncaught_error_behavior = \"dialog\"\n",
+            |writer| writer.set_uncaught_error_behavior(UncaughtErrorBehavior::Ignore),
+            "uncaught_error_behavior = \"ignore\"\n",
+        );
+    }
+
+    #[test]
+    fn set_auto_reload_on_change() {
+        test(
+            "",
+            |writer| writer.set_auto_reload_on_change(true),
+            "auto_reload_on_change = true\n",
+        );
+        test(
+            "auto_reload_on_change = true",
+            |writer| writer.set_auto_reload_on_change(false),
+            "auto_reload_on_change = false\n",
+        );
+    }
+
+    #[test]
+    fn set_spoof_player_version() {
+        test(
+            "",
+            |writer| writer.set_spoof_player_version(Some((32, 0, 0, 465))),
+            "spoof_player_version = \"32.0.0.465\"\n",
+        );
+        test(
+            "spoof_player_version = \"32.0.0.465\"\n",
+            |writer| writer.set_spoof_player_version(Some((11, 2, 202, 644))),
+            "spoof_player_version = \"11.2.202.644\"\n",
+        );
+        test(
+            "spoof_player_version = \"32.0.0.465\"\n",
+            |writer| writer.set_spoof_player_version(None),
+            "",
+        );
+    }
+
+    #[test]
+    fn set_as_api_version() {
+        test(
+            "",
+            |writer| writer.set_as_api_version(Some(12)),
+            "as_api_version = 12\n",
+        );
+        test(
+            "as_api_version = 12\n",
+            |writer| writer.set_as_api_version(None),
+            "",
+        );
+    }
+
+    #[test]
+    fn set_hide_cursor_after_ms() {
+        test(
+            "",
+            |writer| writer.set_hide_cursor_after_ms(Some(3000)),
+            "hide_cursor_after_ms = 3000\n",
+        );
+        test(
+            "hide_cursor_after_ms = 3000\n",
+            |writer| writer.set_hide_cursor_after_ms(Some(0)),
+            "hide_cursor_after_ms = 0\n",
+        );
+        test(
+            "hide_cursor_after_ms = 0\n",
+            |writer| writer.set_hide_cursor_after_ms(None),
+            "",
+        );
+    }
+
+    #[test]
+    fn set_max_execution_duration_secs() {
+        test(
+            "",
+            |writer| writer.set_max_execution_duration_secs(Some(15.0)),
+            "max_execution_duration_secs = 15.0\n",
+        );
+        test(
+            "max_execution_duration_secs = 15.0\n",
+            |writer| writer.set_max_execution_duration_secs(None),
+            "",
+        );
+    }
+
+    #[test]
+    fn set_max_content_memory_mb() {
+        test(
+            "",
+            |writer| writer.set_max_content_memory_mb(Some(512)),
+            "max_content_memory_mb = 512\n",
+        );
+        test(
+            "max_content_memory_mb = 512\n",
+            |writer| writer.set_max_content_memory_mb(Some(1)),
+            "max_content_memory_mb = 16\n",
+        );
+        test(
+            "max_content_memory_mb = 512\n",
+            |writer| writer.set_max_content_memory_mb(None),
+            "",
+        );
+    }
+
+    #[test]
+    fn set_max_texture_size() {
+        test(
+            "",
+            |writer| writer.set_max_texture_size(Some(4096)),
+            "max_texture_size = 4096\n",
+        );
+        test(
+            "max_texture_size = 4096\n",
+            |writer| writer.set_max_texture_size(Some(3000)),
+            "max_texture_size = 2048\n",
+        );
+        test(
+            "",
+            |writer| writer.set_max_texture_size(Some(1)),
+            "max_texture_size = 256\n",
+        );
+        test(
+            "max_texture_size = 4096\n",
+            |writer| writer.set_max_texture_size(None),
+            "",
+        );
+    }
+
+    #[test]
+    fn set_max_frame_rate() {
+        test(
+            "",
+            |writer| writer.set_max_frame_rate(Some(60)),
+            "max_frame_rate = 60\n",
+        );
+        test(
+            "max_frame_rate = 60\n",
+            |writer| writer.set_max_frame_rate(Some(30)),
+            "max_frame_rate = 30\n",
+        );
+        test(
+            "max_frame_rate = 30\n",
+            |writer| writer.set_max_frame_rate(None),
+            "",
+        );
+    }
+
+    #[test]
+    fn set_input_poll_hz() {
+        test(
+            "",
+            |writer| writer.set_input_poll_hz(Some(120)),
+            "input_poll_hz = 120\n",
+        );
+        test(
+            "input_poll_hz = 120\n",
+            |writer| writer.set_input_poll_hz(Some(1)),
+            "input_poll_hz = 10\n",
+        );
+        test(
+            "",
+            |writer| writer.set_input_poll_hz(Some(5000)),
+            "input_poll_hz = 1000\n",
+        );
+        test(
+            "input_poll_hz = 1000\n",
+            |writer| writer.set_input_poll_hz(None),
+            "",
+        );
+    }
+
+    #[test]
+    fn push_network_rule() {
+        test(
+            "",
+            |writer| {
+                writer.push_network_rule(NetworkRule {
+                    pattern: "https://example.com/*".to_string(),
+                    allow: true,
+                })
+            },
+            "[[network_rule]]\npattern = \"https://example.com/*\"\nallow = true\n",
+        );
+        test(
+            "[[network_rule]]\npattern = \"https://example.com/*\"\nallow = true\n",
+            |writer| {
+                writer.push_network_rule(NetworkRule {
+                    pattern: "*".to_string(),
+                    allow: false,
+                })
+            },
+            "[[network_rule]]\npattern = \"https://example.com/*\"\nallow = true\n\n[[network_rule]]\npattern = \"*\"\nallow = false\n",
+        );
+    }
+
+    #[test]
+    fn clear_network_rules() {
+        test(
+            "volume = 0.5\n\n[[network_rule]]\npattern = \"*\"\nallow = false\n",
+            |writer| writer.clear_network_rules(),
+            "volume = 0.5\n",
+        );
+        test("", |writer| writer.clear_network_rules(), "");
+    }
+
+    #[test]
+    fn set_movie_zoom() {
+        test(
+            "",
+            |writer| {
+                writer.set_movie_zoom("/movies/a.swf".to_string(), 1.5);
+                writer.set_movie_zoom("/movies/b.swf".to_string(), 2.0);
+            },
+            "[movie_zoom]\n\"/movies/a.swf\" = 1.5\n\"/movies/b.swf\" = 2.0\n",
+        );
+        test(
+            "[movie_zoom]\n\"/movies/a.swf\" = 1.5\n",
+            |writer| writer.set_movie_zoom("/movies/a.swf".to_string(), 0.5),
+            "[movie_zoom]\n\"/movies/a.swf\" = 0.5\n",
+        );
+        test(
+            "",
+            |writer| {
+                writer.set_movie_zoom("/movies/a.swf".to_string(), 100.0);
+                writer.set_movie_zoom("/movies/b.swf".to_string(), 0.0);
+            },
+            "[movie_zoom]\n\"/movies/a.swf\" = 8.0\n\"/movies/b.swf\" = 0.25\n",
+        );
+    }
+
+    #[test]
+    fn clear_movie_zoom() {
+        test(
+            "[movie_zoom]\n\"/movies/a.swf\" = 1.5\n\"/movies/b.swf\" = 2.0\n",
+            |writer| writer.clear_movie_zoom("/movies/a.swf"),
+            "[movie_zoom]\n\"/movies/b.swf\" = 2.0\n",
+        );
+        test("", |writer| writer.clear_movie_zoom("/movies/a.swf"), "");
+    }
+
+    #[test]
+    fn set_default_main_swf() {
+        test(
+            "",
+            |writer| {
+                writer.set_default_main_swf("/games/b.zip".to_string(), "b.swf".to_string());
+                writer.set_default_main_swf("/games/a.zip".to_string(), "a.swf".to_string());
+            },
+            "[default_main_swf]\n\"/games/b.zip\" = \"b.swf\"\n\"/games/a.zip\" = \"a.swf\"\n",
+        );
+        test(
+            "[default_main_swf]\n\"/games/a.zip\" = \"a.swf\"\n",
+            |writer| {
+                writer.set_default_main_swf("/games/a.zip".to_string(), "other.swf".to_string())
+            },
+            "[default_main_swf]\n\"/games/a.zip\" = \"other.swf\"\n",
+        );
+    }
+
+    #[test]
+    fn clear_default_main_swf() {
+        test(
+            "[default_main_swf]\n\"/games/a.zip\" = \"a.swf\"\n\"/games/b.zip\" = \"b.swf\"\n",
+            |writer| writer.clear_default_main_swf("/games/a.zip"),
+            "[default_main_swf]\n\"/games/b.zip\" = \"b.swf\"\n",
+        );
+        test(
+            "",
+            |writer| writer.clear_default_main_swf("/games/a.zip"),
+            "",
+        );
+    }
+
+    #[test]
+    fn set_audio_sample_rate() {
+        test(
+            "",
+            |writer| writer.set_audio_sample_rate(Some(48000)),
+            "audio = { sample_rate = 48000 }\n",
+        );
+        test(
+            "audio = { sample_rate = 48000 }\n",
+            |writer| writer.set_audio_sample_rate(Some(44100)),
+            "audio = { sample_rate = 44100 }\n",
+        );
+        test(
+            "audio = { sample_rate = 44100 }\n",
+            |writer| writer.set_audio_sample_rate(None),
+            "audio = {}\n",
+        );
+        test(
+            "[audio]\nsample_rate = 44100\n",
+            |writer| writer.set_audio_sample_rate(Some(48000)),
+            "[audio]\nsample_rate = 48000\n",
+        );
+        test(
+            "[audio]\nsample_rate = 48000\n",
+            |writer| writer.set_audio_sample_rate(None),
+            "[audio]\n",
+        );
+        test("", |writer| writer.set_audio_sample_rate(None), "");
+    }
+
+    #[test]
+    fn set_log_filename_pattern() {
+        test(
+            "",
+            |writer| writer.set_log_filename_pattern(FilenamePattern::WithTimestamp),
+            "log = { filename_pattern = \"with_timestamp\" }\n",
+        );
+        test(
+            "log = { filename_pattern = \"with_timestamp\" }\n",
+            |writer| writer.set_log_filename_pattern(FilenamePattern::SingleFile),
+            "log = { filename_pattern = \"single_file\" }\n",
+        );
+        test(
+            "[log]\nfilename_pattern = \"with_timestamp\"\n",
+            |writer| writer.set_log_filename_pattern(FilenamePattern::SingleFile),
+            "[log]\nfilename_pattern = \"single_file\"\n",
+        );
+    }
+
+    #[test]
+    fn set_log_level() {
+        for (level, name) in [
+            (LogLevel::Error, "error"),
+            (LogLevel::Warn, "warn"),
+            (LogLevel::Info, "info"),
+            (LogLevel::Debug, "debug"),
+            (LogLevel::Trace, "trace"),
+        ] {
+            test(
+                "",
+                |writer| writer.set_log_level(level),
+                &format!("log = {{ level = \"{name}\" }}\n"),
+            );
+        }
+        test(
+            "[log]\nfilename_pattern = \"with_timestamp\"\n",
+            |writer| writer.set_log_level(LogLevel::Debug),
+            "[log]\nfilename_pattern = \"with_timestamp\"\nlevel = \"debug\"\n",
+        );
+        test(
+            "[log]\nlevel = \"debug\"\nfilename_pattern = \"with_timestamp\"\n",
+            |writer| writer.set_log_filename_pattern(FilenamePattern::SingleFile),
+            "[log]\nlevel = \"debug\"\nfilename_pattern = \"single_file\"\n",
+        );
+    }
+
+    #[test]
+    fn set_log_enabled() {
+        test(
+            "",
+            |writer| writer.set_log_enabled(false),
+            "log = { enabled = false }\n",
+        );
+        test(
+            "[log]\nfilename_pattern = \"with_timestamp\"\n",
+            |writer| writer.set_log_enabled(false),
+            "[log]\nfilename_pattern = \"with_timestamp\"\nenabled = false\n",
+        );
+        test(
+            "[log]\nenabled = false\nfilename_pattern = \"single_file\"\n",
+            |writer| writer.set_log_enabled(true),
+            "[log]\nenabled = true\nfilename_pattern = \"single_file\"\n",
+        );
+    }
+
+    #[test]
+    fn set_storage_backend() {
+        test(
+            "",
+            |writer| writer.set_storage_backend(StorageBackend::Disk),
+            "storage = { backend = \"disk\" }\n",
+        );
+        test(
+            "storage = { backend = \"disk\" }\n",
+            |writer| writer.set_storage_backend(StorageBackend::Memory),
+            "storage = { backend = \"memory\" }\n",
+        );
+        test(
+            "[storage]\nbackend = \"disk\"\n",
+            |writer| writer.set_storage_backend(StorageBackend::Memory),
+            "[storage]\nbackend = \"memory\"\n",
+        );
+    }
+
+    #[test]
+    fn set_domain_storage_backend() {
+        test(
+            "",
+            |writer| {
+                writer.set_domain_storage_backend("example.com".to_string(), StorageBackend::Memory)
+            },
+            "storage = { per_domain_overrides = { \"example.com\" = \"memory\" } }\n",
+        );
+        test(
+            "[storage]\nbackend = \"disk\"\n",
+            |writer| {
+                writer.set_domain_storage_backend(
+                    "example.com".to_string(),
+                    StorageBackend::Memory,
+                );
+                writer.set_domain_storage_backend("ruffle.rs".to_string(), StorageBackend::Disk);
+            },
+            "[storage]\nbackend = \"disk\"\n\n[storage.per_domain_overrides]\n\"example.com\" = \"memory\"\n\"ruffle.rs\" = \"disk\"\n",
+        );
+    }
+
+    #[test]
+    fn clear_domain_storage_backend() {
+        test(
+            "[storage]\nbackend = \"disk\"\n\n[storage.per_domain_overrides]\n\"example.com\" = \"memory\"\n\"ruffle.rs\" = \"disk\"\n",
+            |writer| writer.clear_domain_storage_backend("example.com"),
+            "[storage]\nbackend = \"disk\"\n\n[storage.per_domain_overrides]\n\"ruffle.rs\" = \"disk\"\n",
+        );
+        test(
+            "",
+            |writer| writer.clear_domain_storage_backend("example.com"),
+            "",
         );
     }
 }
@@ -150,6 +150,7 @@ pub struct PlayerOptions {
     with_audio: bool,
     with_video: bool,
     runtime: PlayerRuntime,
+    avm2_max_recursion_depth: Option<usize>,
 }
 
 impl PlayerOptions {
@@ -174,6 +175,10 @@ impl PlayerOptions {
 
         player_builder = player_builder.with_player_runtime(self.runtime);
 
+        if let Some(max_recursion_depth) = self.avm2_max_recursion_depth {
+            player_builder = player_builder.with_avm2_max_recursion_depth(max_recursion_depth);
+        }
+
         #[cfg(feature = "ruffle_video_software")]
         if self.with_video {
             use ruffle_video_software::backend::SoftwareVideoBackend;
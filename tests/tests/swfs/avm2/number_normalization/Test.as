@@ -0,0 +1,43 @@
+package {
+	import flash.display.MovieClip;
+	import flash.utils.describeType;
+	import flash.utils.getQualifiedClassName;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var half:* = 2.5;
+			var ten:* = 10;
+			var four:* = 4;
+			var max:* = int.MAX_VALUE;
+			var min:* = int.MIN_VALUE;
+			var cube:* = Math.pow(2, 3);
+			var fraction:* = 1.5;
+
+			describe("half * 2", half * 2, 5);
+			describe("half + half", half + half, 5);
+			describe("ten - half * 2", ten - half * 2, 5);
+			describe("ten / four", ten / four, 2.5);
+			describe("ten / half", ten / half, 4);
+			describe("ten % half", ten % half, 0);
+			describe("-(half * 2)", -(half * 2), -5);
+			describe("int.MAX_VALUE + 1", max + 1, 2147483648);
+			describe("int.MIN_VALUE - 1", min - 1, -2147483649);
+
+			cube++;
+			describe("Math.pow(2, 3)++", cube, 9);
+			cube--;
+			describe("Math.pow(2, 3)--", cube, 8);
+			fraction++;
+			describe("1.5++", fraction, 2.5);
+		}
+
+		private function describe(name:String, value:*, expected:*):void {
+			trace("// " + name);
+			trace(value);
+			trace(value === expected);
+			trace(value is int);
+			trace(getQualifiedClassName(value));
+			trace(describeType(value).@name);
+		}
+	}
+}
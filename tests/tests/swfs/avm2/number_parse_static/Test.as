@@ -0,0 +1,38 @@
+// compiled with mxmlc
+
+package {
+    public class Test {}
+}
+
+trace("// Number.parseInt(\"0xFF\", 16)");
+trace(Number.parseInt("0xFF", 16));
+
+trace("// Number.parseInt(\"0xFF\")");
+trace(Number.parseInt("0xFF"));
+
+trace("// Number.parseInt(\"  -42\")");
+trace(Number.parseInt("  -42"));
+
+trace("// Number.parseInt(\"abc\", 16)");
+trace(Number.parseInt("abc", 16));
+
+trace("// Number.parseInt(\"\")");
+trace(Number.parseInt(""));
+
+trace("// Number.parseInt(\"abc\")");
+trace(Number.parseInt("abc"));
+
+trace("// Number.parseInt()");
+trace(Number.parseInt());
+
+trace("// Number.parseFloat(\"  -1.5e3\")");
+trace(Number.parseFloat("  -1.5e3"));
+
+trace("// Number.parseFloat(\"\")");
+trace(Number.parseFloat(""));
+
+trace("// Number.parseFloat(\"abc\")");
+trace(Number.parseFloat("abc"));
+
+trace("// Number.parseFloat()");
+trace(Number.parseFloat());
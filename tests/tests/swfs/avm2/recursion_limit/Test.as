@@ -0,0 +1,32 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		private var depth:int = 0;
+
+		public function Test() {
+			try {
+				recurse();
+				trace("recursion finished without an error");
+			} catch (e:Error) {
+				trace("caught " + e.errorID);
+				trace(e.message);
+				trace("recursed at least 100 calls: " + (depth >= 100));
+			}
+
+			// The call stack unwinds fully once the error is caught.
+			depth = 0;
+			trace("countdown(10): " + countdown(10));
+		}
+
+		private function recurse():void {
+			depth++;
+			recurse();
+		}
+
+		private function countdown(n:int):int {
+			depth++;
+			return n == 0 ? depth : countdown(n - 1);
+		}
+	}
+}
@@ -0,0 +1,31 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		private var depth:int = 0;
+
+		public function Test() {
+			try {
+				recurse();
+				trace("recursion finished without an error");
+			} catch (e:Error) {
+				trace("caught " + e.errorID);
+				trace("recursed " + depth + " calls");
+			}
+
+			// Recursing to just under the limit still succeeds.
+			depth = 0;
+			trace("countdown(40): " + countdown(40));
+		}
+
+		private function recurse():void {
+			depth++;
+			recurse();
+		}
+
+		private function countdown(n:int):int {
+			depth++;
+			return n == 0 ? depth : countdown(n - 1);
+		}
+	}
+}